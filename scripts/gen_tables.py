@@ -2,10 +2,10 @@
 
 """
 Script for generating the pre-computed tables used by microfft:
-  - radix-2 FFT twiddle tables
+  - radix-2 FFT twiddle tables (`f32`, `f64`, Q15 and Q31 fixed-point)
   - bit reversal tables
 
-Used to create the file `src/tables.rs`.
+Used to create the file `src/tables.rs`, after formatting with rustfmt.
 """
 
 import argparse
@@ -21,22 +21,55 @@ def parse_args():
 def emit_sine(max_n):
     n = 4
     while n <= max_n:
-        emit_sine_table(n)
+        emit_sine_table(n, "SINE", "f32", f'feature = "maxn-{n}"')
         n *= 2
 
 
-def emit_sine_table(n):
-    print(f'#[cfg(feature = "maxn-{n}")]')
+def emit_sine_f64(max_n):
+    n = 4
+    while n <= max_n:
+        cfg = f'all(feature = "f64", feature = "maxn-{n}")'
+        emit_sine_table(n, "SINE_F64", "f64", cfg)
+        n *= 2
+
+
+def emit_sine_q15(max_n):
+    n = 4
+    while n <= max_n:
+        cfg = f'all(feature = "q15", feature = "maxn-{n}")'
+        emit_sine_table(n, "SINE_Q15", "i16", cfg, to_fixed(15))
+        n *= 2
+
+
+def emit_sine_q31(max_n):
+    n = 4
+    while n <= max_n:
+        cfg = f'all(feature = "q31", feature = "maxn-{n}")'
+        emit_sine_table(n, "SINE_Q31", "i32", cfg, to_fixed(31))
+        n *= 2
+
+
+def emit_sine_table(n, name, ty, cfg, convert=lambda x: x):
+    print(f"#[cfg({cfg})]")
     print(f"#[allow(clippy::excessive_precision)]")
     print(f"#[allow(clippy::unreadable_literal)]")
-    print("pub(crate) const SINE: &[f32] = &[")
+    print(f"pub(crate) const {name}: &[{ty}] = &[")
     for k in range(1, n // 4):
         sine = math.sin(-2 * math.pi * k / n)
-        print(f"        {sine},")
+        print(f"        {convert(sine)},")
     print("];")
     print()
 
 
+def to_fixed(frac_bits):
+    scale = 1 << frac_bits
+
+    def convert(x):
+        return max(-scale, min(scale - 1, round(x * scale)))
+
+    return convert
+
+
 def emit_bitrev(max_n):
     print('#[cfg(feature = "bitrev-tables")]')
     print("pub(crate) const BITREV: &[&[u16]] = &[")
@@ -71,6 +104,9 @@ def reverse_bits(num, nbits):
 def main():
     args = parse_args()
     emit_sine(args.N)
+    emit_sine_f64(args.N)
+    emit_sine_q15(args.N)
+    emit_sine_q31(args.N)
     emit_bitrev(args.N)
 
 
@@ -0,0 +1,88 @@
+use crate::{cfft::MAX_N, complex::try_cfft, math, FftError};
+use num_complex::Complex32;
+
+/// Return the size of the power-of-two FFTs used to compute an `n`-point DFT.
+pub(crate) const fn fft_len(n: usize) -> usize {
+    if n < 2 {
+        0
+    } else {
+        (2 * n - 1).next_power_of_two()
+    }
+}
+
+/// Compute the DFT of an input of arbitrary size using Bluestein's algorithm.
+///
+/// The DFT is expressed as a convolution of the input with a chirp signal,
+/// which is computed using power-of-two FFTs on the zero-padded sequences
+/// in `scratch`.
+pub(crate) fn transform(x: &mut [Complex32], scratch: &mut [Complex32]) -> Result<(), FftError> {
+    let n = x.len();
+    if n == 0 {
+        return Err(FftError::TooSmall);
+    } else if n == 1 {
+        return Ok(());
+    }
+
+    let m = fft_len(n);
+    if m > MAX_N {
+        return Err(FftError::TooLarge);
+    } else if scratch.len() < 2 * m {
+        return Err(FftError::ScratchTooSmall);
+    }
+
+    let (a, b) = scratch[..(2 * m)].split_at_mut(m);
+    a.iter_mut().for_each(|c| *c = Complex32::default());
+    b.iter_mut().for_each(|c| *c = Complex32::default());
+
+    // a[k] = x[k] * chirp[k], b[k] = b[m - k] = conj(chirp[k])
+    for k in 0..n {
+        let w = chirp(k, n);
+        a[k] = x[k] * w;
+        b[k] = w.conj();
+        if k > 0 {
+            b[m - k] = w.conj();
+        }
+    }
+
+    convolve(a, b);
+
+    for (k, x_k) in x.iter_mut().enumerate() {
+        *x_k = chirp(k, n) * a[k];
+    }
+    Ok(())
+}
+
+/// Compute the circular convolution of `a` and `b`, storing it in `a`.
+///
+/// Both inputs must have the same power-of-two length. The contents of `b`
+/// are overwritten by its FFT.
+pub(crate) fn convolve(a: &mut [Complex32], b: &mut [Complex32]) {
+    debug_assert_eq!(a.len(), b.len());
+
+    let result = try_cfft(a).and(try_cfft(b));
+    debug_assert!(result.is_ok());
+
+    // inverse FFT of the product, computed as the conjugate of the FFT of
+    // the conjugated product
+    for (a_k, b_k) in a.iter_mut().zip(b.iter()) {
+        *a_k = (*a_k * b_k).conj();
+    }
+    let result = try_cfft(a);
+    debug_assert!(result.is_ok());
+
+    let scale = 1. / a.len() as f32;
+    for a_k in a.iter_mut() {
+        *a_k = a_k.conj() * scale;
+    }
+}
+
+/// Compute the chirp factor `e^(-πi * k² / n)`.
+fn chirp(k: usize, n: usize) -> Complex32 {
+    // reduce `k²` modulo the period `2n` without overflowing
+    let period = 2 * n as u64;
+    let k = k as u64 % period;
+    let k2 = (k * k % period) as usize;
+
+    let (cos, sin) = math::cos_sin(k2, 2 * n);
+    Complex32::new(cos, -sin)
+}
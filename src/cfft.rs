@@ -1,5 +1,10 @@
-use crate::tables;
-use num_complex::Complex32;
+#[cfg(any(feature = "q15", feature = "q31"))]
+use crate::fixed::Fixed;
+use crate::{float::Float, sealed::Sealed, Fft, Scaling};
+use num_complex::{Complex, Complex32};
+
+/// The maximum CFFT size, as selected by the `maxn-*` features.
+pub(crate) const MAX_N: usize = (crate::tables::SINE.len() + 1) * 4;
 
 pub(crate) trait CFft {
     type Half: CFft;
@@ -8,20 +13,46 @@ pub(crate) trait CFft {
     const LOG2_N: usize;
 
     #[cfg(feature = "bitrev-tables")]
-    const BITREV_TABLE: &'static [u16] = tables::BITREV[Self::LOG2_N];
+    const BITREV_TABLE: &'static [u16] = crate::tables::BITREV[Self::LOG2_N];
+
+    #[inline]
+    fn transform<T: Float>(x: &mut [Complex<T>]) -> &mut [Complex<T>] {
+        debug_assert_eq!(x.len(), Self::N);
+
+        Self::bit_reverse_reorder(x);
+        Self::compute_butterflies(x, false);
+        x
+    }
+
+    #[inline]
+    fn inverse_transform<T: Float>(x: &mut [Complex<T>], scaling: Scaling) -> &mut [Complex<T>] {
+        debug_assert_eq!(x.len(), Self::N);
 
+        Self::bit_reverse_reorder(x);
+        Self::compute_butterflies(x, true);
+
+        if scaling == Scaling::Normalized {
+            let scale = T::one() / T::from_usize(Self::N);
+            for c in x.iter_mut() {
+                *c *= scale;
+            }
+        }
+        x
+    }
+
+    #[cfg(any(feature = "q15", feature = "q31"))]
     #[inline]
-    fn transform(x: &mut [Complex32]) -> &mut [Complex32] {
+    fn transform_fixed<T: Fixed>(x: &mut [Complex<T>]) -> &mut [Complex<T>] {
         debug_assert_eq!(x.len(), Self::N);
 
         Self::bit_reverse_reorder(x);
-        Self::compute_butterflies(x);
+        Self::compute_butterflies_fixed(x);
         x
     }
 
     #[cfg(feature = "bitrev-tables")]
     #[inline]
-    fn bit_reverse_reorder(x: &mut [Complex32]) {
+    fn bit_reverse_reorder<T>(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), Self::N);
 
         for i in 0..Self::N {
@@ -32,7 +63,7 @@ pub(crate) trait CFft {
 
     #[cfg(not(feature = "bitrev-tables"))]
     #[inline]
-    fn bit_reverse_reorder(x: &mut [Complex32]) {
+    fn bit_reverse_reorder<T>(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), Self::N);
 
         let shift = core::mem::size_of::<usize>() * 8 - Self::LOG2_N;
@@ -46,17 +77,21 @@ pub(crate) trait CFft {
     }
 
     #[inline]
-    fn compute_butterflies(x: &mut [Complex32]) {
+    fn compute_butterflies<T: Float>(x: &mut [Complex<T>], inverse: bool) {
         debug_assert_eq!(x.len(), Self::N);
 
         let m = Self::N / 2;
         let u = m / 2;
 
-        let table_len = tables::SINE.len();
+        let table_len = T::SINE.len();
         let table_stride = (table_len + 1) * 4 / Self::N;
 
-        Self::Half::compute_butterflies(&mut x[..m]);
-        Self::Half::compute_butterflies(&mut x[m..]);
+        // the imaginary parts of all twiddle factors flip their sign
+        // for the inverse transform
+        let sign = if inverse { -T::one() } else { T::one() };
+
+        Self::Half::compute_butterflies(&mut x[..m], inverse);
+        Self::Half::compute_butterflies(&mut x[m..], inverse);
 
         // [k = 0] twiddle factor: `1 + 0i`
         let (x_0, x_m) = (x[0], x[m]);
@@ -68,9 +103,9 @@ pub(crate) trait CFft {
         //   - im from SINE table directly
         for k in 1..u {
             let s = k * table_stride;
-            let re = tables::SINE[table_len - s] * -1.;
-            let im = tables::SINE[s - 1];
-            let twiddle = Complex32::new(re, im);
+            let re = -T::SINE[table_len - s];
+            let im = T::SINE[s - 1] * sign;
+            let twiddle = Complex::new(re, im);
 
             let (x_k, x_km) = (x[k], x[k + m]);
             let y = twiddle * x_km;
@@ -78,9 +113,9 @@ pub(crate) trait CFft {
             x[k + m] = x_k - y;
         }
 
-        // [k = m/2] twiddle factor: `0 - 1i`
+        // [k = m/2] twiddle factor: `0 - 1i` (`0 + 1i` if inverse)
         let (x_u, x_um) = (x[u], x[u + m]);
-        let y = x_um * Complex32::new(0., -1.);
+        let y = x_um * Complex::new(T::zero(), -sign);
         x[u] = x_u + y;
         x[u + m] = x_u - y;
 
@@ -89,9 +124,9 @@ pub(crate) trait CFft {
         //   - im from SINE table backwards
         for k in (u + 1)..m {
             let s = (k - u) * table_stride;
-            let re = tables::SINE[s - 1];
-            let im = tables::SINE[table_len - s];
-            let twiddle = Complex32::new(re, im);
+            let re = T::SINE[s - 1];
+            let im = T::SINE[table_len - s] * sign;
+            let twiddle = Complex::new(re, im);
 
             let (x_k, x_km) = (x[k], x[k + m]);
             let y = twiddle * x_km;
@@ -99,6 +134,96 @@ pub(crate) trait CFft {
             x[k + m] = x_k - y;
         }
     }
+
+    // Same as `compute_butterflies`, except that the outputs of each stage
+    // are halved to prevent overflows.
+    #[cfg(any(feature = "q15", feature = "q31"))]
+    #[inline]
+    fn compute_butterflies_fixed<T: Fixed>(x: &mut [Complex<T>]) {
+        debug_assert_eq!(x.len(), Self::N);
+
+        let m = Self::N / 2;
+        let u = m / 2;
+
+        let table_len = T::SINE.len();
+        let table_stride = (table_len + 1) * 4 / Self::N;
+
+        Self::Half::compute_butterflies_fixed(&mut x[..m]);
+        Self::Half::compute_butterflies_fixed(&mut x[m..]);
+
+        // [k = 0] twiddle factor: `1 + 0i`
+        let (x_0, x_m) = (x[0], x[m]);
+        x[0] = T::half_sum(x_0, x_m);
+        x[m] = T::half_diff(x_0, x_m);
+
+        // [k in [1, m/2)] twiddle factor:
+        //   - re from SINE table backwards and negative
+        //   - im from SINE table directly
+        for k in 1..u {
+            let s = k * table_stride;
+            let re = T::SINE[table_len - s].neg();
+            let im = T::SINE[s - 1];
+            let twiddle = Complex::new(re, im);
+
+            let (x_k, x_km) = (x[k], x[k + m]);
+            let y = T::mul(x_km, twiddle);
+            x[k] = T::half_sum(x_k, y);
+            x[k + m] = T::half_diff(x_k, y);
+        }
+
+        // [k = m/2] twiddle factor: `0 - 1i`
+        let (x_u, x_um) = (x[u], x[u + m]);
+        let y = Complex::new(x_um.im, x_um.re.neg());
+        x[u] = T::half_sum(x_u, y);
+        x[u + m] = T::half_diff(x_u, y);
+
+        // [k in (m/2, m)] twiddle factor:
+        //   - re from SINE table directly
+        //   - im from SINE table backwards
+        for k in (u + 1)..m {
+            let s = (k - u) * table_stride;
+            let re = T::SINE[s - 1];
+            let im = T::SINE[table_len - s];
+            let twiddle = Complex::new(re, im);
+
+            let (x_k, x_km) = (x[k], x[k + m]);
+            let y = T::mul(x_km, twiddle);
+            x[k] = T::half_sum(x_k, y);
+            x[k + m] = T::half_diff(x_k, y);
+        }
+    }
+}
+
+/// Look up the twiddle factor `e^(-2πi * k / n)` for `k` in `[0, n)`, or its
+/// conjugate if `inverse` is set.
+///
+/// `n` must be a power of two not exceeding `MAX_N`.
+#[inline]
+pub(crate) fn twiddle<T: Float>(k: usize, n: usize, inverse: bool) -> Complex<T> {
+    let table_len = T::SINE.len();
+    let quarter = table_len + 1;
+    let s = k * quarter * 4 / n;
+
+    // `sin(2π * s / (4 * quarter))` for `s` in `[0, quarter]`
+    let sin = |s: usize| match s {
+        0 => T::zero(),
+        s if s == quarter => T::one(),
+        s => -T::SINE[s - 1],
+    };
+
+    let (r, rem) = (s / quarter, s % quarter);
+    let (cos, sin) = match r {
+        0 => (sin(quarter - rem), sin(rem)),
+        1 => (-sin(rem), sin(quarter - rem)),
+        2 => (-sin(quarter - rem), -sin(rem)),
+        _ => (sin(rem), -sin(quarter - rem)),
+    };
+
+    if inverse {
+        Complex::new(cos, sin)
+    } else {
+        Complex::new(cos, -sin)
+    }
 }
 
 pub(crate) struct CFftN1;
@@ -110,17 +235,24 @@ impl CFft for CFftN1 {
     const LOG2_N: usize = 0;
 
     #[inline]
-    fn bit_reverse_reorder(x: &mut [Complex32]) {
+    fn bit_reverse_reorder<T>(x: &mut [Complex<T>]) {
+        debug_assert_eq!(x.len(), 1);
+    }
+
+    #[inline]
+    fn compute_butterflies<T: Float>(x: &mut [Complex<T>], _inverse: bool) {
         debug_assert_eq!(x.len(), 1);
     }
 
+    #[cfg(any(feature = "q15", feature = "q31"))]
     #[inline]
-    fn compute_butterflies(x: &mut [Complex32]) {
+    fn compute_butterflies_fixed<T: Fixed>(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), 1);
     }
 }
 
-pub(crate) struct CFftN2;
+/// Size marker type of the 2-point CFFT.
+pub struct CFftN2;
 
 impl CFft for CFftN2 {
     type Half = CFftN1;
@@ -129,20 +261,31 @@ impl CFft for CFftN2 {
     const LOG2_N: usize = 1;
 
     #[inline]
-    fn compute_butterflies(x: &mut [Complex32]) {
+    fn compute_butterflies<T: Float>(x: &mut [Complex<T>], _inverse: bool) {
         debug_assert_eq!(x.len(), 2);
 
         let (x_0, x_1) = (x[0], x[1]);
         x[0] = x_0 + x_1;
         x[1] = x_0 - x_1;
     }
+
+    #[cfg(any(feature = "q15", feature = "q31"))]
+    #[inline]
+    fn compute_butterflies_fixed<T: Fixed>(x: &mut [Complex<T>]) {
+        debug_assert_eq!(x.len(), 2);
+
+        let (x_0, x_1) = (x[0], x[1]);
+        x[0] = T::half_sum(x_0, x_1);
+        x[1] = T::half_diff(x_0, x_1);
+    }
 }
 
 macro_rules! cfft_impls {
     ( $( $I:expr => ($N:expr, $CFftN:ident, $Half:ident), )* ) => {
         $(
+            /// Size marker type of a CFFT.
             #[allow(dead_code)]
-            pub(crate) struct $CFftN;
+            pub struct $CFftN;
 
             impl CFft for $CFftN {
                 type Half = $Half;
@@ -166,4 +309,214 @@ cfft_impls! {
     10 => (1024, CFftN1024, CFftN512),
     11 => (2048, CFftN2048, CFftN1024),
     12 => (4096, CFftN4096, CFftN2048),
+    13 => (8192, CFftN8192, CFftN4096),
+    14 => (16384, CFftN16384, CFftN8192),
+    15 => (32768, CFftN32768, CFftN16384),
+    16 => (65536, CFftN65536, CFftN32768),
+}
+
+/// Array of `Complex32` values of a supported CFFT size.
+///
+/// This trait is implemented by `[Complex32; N]` for all sizes `N` supported
+/// by the selected `maxn-*` feature. It allows writing code that is generic
+/// over the size of the array. The trait is sealed and cannot be implemented
+/// outside of microfft.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::CFftArray, Complex32};
+///
+/// fn dc<const N: usize>(input: &mut [Complex32; N]) -> Complex32
+/// where
+///     [Complex32; N]: CFftArray,
+/// {
+///     input.transform();
+///     input[0]
+/// }
+///
+/// let mut input = [Complex32::new(1., 0.); 16];
+/// assert_eq!(dc(&mut input), Complex32::new(16., 0.));
+/// ```
+pub trait CFftArray: Sealed {
+    /// Perform an in-place CFFT.
+    fn transform(&mut self);
+}
+
+macro_rules! cfft_public_impls {
+    ( $( $(#[$meta:meta])* $N:literal => $CFftN:ident, )* ) => {
+        $(
+            $(#[$meta])*
+            impl Sealed for [Complex32; $N] {}
+
+            $(#[$meta])*
+            impl CFftArray for [Complex32; $N] {
+                #[inline]
+                fn transform(&mut self) {
+                    <$CFftN as CFft>::transform(self);
+                }
+            }
+
+            $(#[$meta])*
+            impl Sealed for $CFftN {}
+
+            $(#[$meta])*
+            impl Fft for $CFftN {
+                type Input = [Complex32; $N];
+                type Output = [Complex32; $N];
+
+                const N: usize = <Self as CFft>::N;
+                const LOG2_N: usize = <Self as CFft>::LOG2_N;
+
+                #[inline]
+                fn transform(input: &mut Self::Input) -> &mut Self::Output {
+                    <Self as CFft>::transform(input);
+                    input
+                }
+            }
+        )*
+    };
+}
+
+cfft_public_impls! {
+    2 => CFftN2,
+    4 => CFftN4,
+    #[cfg(any(
+        feature = "maxn-8",
+        feature = "maxn-16",
+        feature = "maxn-32",
+        feature = "maxn-64",
+        feature = "maxn-128",
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    8 => CFftN8,
+    #[cfg(any(
+        feature = "maxn-16",
+        feature = "maxn-32",
+        feature = "maxn-64",
+        feature = "maxn-128",
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    16 => CFftN16,
+    #[cfg(any(
+        feature = "maxn-32",
+        feature = "maxn-64",
+        feature = "maxn-128",
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    32 => CFftN32,
+    #[cfg(any(
+        feature = "maxn-64",
+        feature = "maxn-128",
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    64 => CFftN64,
+    #[cfg(any(
+        feature = "maxn-128",
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    128 => CFftN128,
+    #[cfg(any(
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    256 => CFftN256,
+    #[cfg(any(
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    512 => CFftN512,
+    #[cfg(any(
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    1024 => CFftN1024,
+    #[cfg(any(
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    2048 => CFftN2048,
+    #[cfg(any(
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    4096 => CFftN4096,
+    #[cfg(any(
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    8192 => CFftN8192,
+    #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+    16384 => CFftN16384,
+    #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+    32768 => CFftN32768,
+    #[cfg(feature = "maxn-65536")]
+    65536 => CFftN65536,
 }
@@ -1,8 +1,502 @@
 //! FFT on complex inputs (CFFT)
+//!
+//! Next to the forward transforms (`cfft_*`), this module provides the
+//! corresponding inverse transforms (`icfft_*`). The inverse transforms can
+//! either produce unscaled output or scale it by `1/N`, in which case they
+//! exactly undo the forward transform (see [`Scaling`]).
+//!
+//! For inputs whose size is known at compile time, the generic [`cfft`]
+//! function can be used instead of the size-specific ones. It takes a
+//! fixed-size array and fails to compile if the array size is not supported.
+//! The supported array types implement the [`CFftArray`] trait, which can be
+//! used as a bound in code that is generic over the array size.
+//!
+//! If the input size is only known at runtime, [`try_cfft`] dispatches to
+//! the matching size-specific function and returns an error if the size is
+//! not supported.
+//!
+//! Sizes that are not powers of two are supported by [`cfft_mixed`] and
+//! [`icfft_mixed`], as long as they have no prime factors other than 2, 3
+//! and 5. Inputs of any other size can be transformed by [`cfft_any`], using
+//! a caller-provided scratch buffer.
+//!
+//! The size marker types (`CFftN*`) implement the [`Fft`] trait, for
+//! writing code that is generic over the FFT size.
+//!
+//! [`CFftArray`]: trait.CFftArray.html
+//! [`Fft`]: ../trait.Fft.html
+//! [`Scaling`]: ../enum.Scaling.html
+//! [`cfft`]: fn.cfft.html
+//! [`cfft_any`]: fn.cfft_any.html
+//! [`cfft_mixed`]: fn.cfft_mixed.html
+//! [`icfft_mixed`]: fn.icfft_mixed.html
+//! [`try_cfft`]: fn.try_cfft.html
 
-use crate::cfft::*;
+use crate::{bluestein, cfft::*, mixed, FftError, Scaling};
 use num_complex::Complex32;
 
+pub use crate::cfft::{CFftArray, CFftN2, CFftN4};
+
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN8;
+
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN16;
+
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN32;
+
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN64;
+
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN128;
+
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN256;
+
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN512;
+
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN1024;
+
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN2048;
+
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN4096;
+
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::cfft::CFftN8192;
+
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+pub use crate::cfft::CFftN16384;
+
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+pub use crate::cfft::CFftN32768;
+
+#[cfg(feature = "maxn-65536")]
+pub use crate::cfft::CFftN65536;
+
+/// Perform an in-place CFFT on a fixed-size array.
+///
+/// The FFT size is inferred from the size of the input array. Passing an
+/// array whose size is not a power of two or exceeds the size selected by the
+/// `maxn-*` features results in a compile error.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::cfft, Complex32};
+///
+/// let mut input = [Complex32::default(); 16];
+/// let result = cfft(&mut input);
+/// ```
+///
+/// Unsupported sizes are rejected at compile time:
+///
+/// ```compile_fail
+/// use microfft::{complex::cfft, Complex32};
+///
+/// let mut input = [Complex32::default(); 12];
+/// let result = cfft(&mut input);
+/// ```
+#[inline]
+pub fn cfft<const N: usize>(input: &mut [Complex32; N]) -> &mut [Complex32; N]
+where
+    [Complex32; N]: CFftArray,
+{
+    input.transform();
+    input
+}
+
+/// Perform an in-place CFFT on an input of runtime size.
+///
+/// The FFT size is determined by the length of the input slice.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::try_cfft, Complex32, FftError};
+///
+/// let mut input = [Complex32::default(); 16];
+/// let result = try_cfft(&mut input);
+/// assert!(result.is_ok());
+///
+/// let mut input = [Complex32::default(); 12];
+/// let result = try_cfft(&mut input);
+/// assert_eq!(result, Err(FftError::NotPowerOfTwo));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the length of `input` is not a supported FFT size.
+/// Inputs with fewer than two values, including empty ones, are reported as
+/// `FftError::TooSmall`.
+pub fn try_cfft(input: &mut [Complex32]) -> Result<&mut [Complex32], FftError> {
+    let result = match input.len() {
+        2 => cfft_2(input),
+        4 => cfft_4(input),
+        #[cfg(any(
+            feature = "maxn-8",
+            feature = "maxn-16",
+            feature = "maxn-32",
+            feature = "maxn-64",
+            feature = "maxn-128",
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        8 => cfft_8(input),
+        #[cfg(any(
+            feature = "maxn-16",
+            feature = "maxn-32",
+            feature = "maxn-64",
+            feature = "maxn-128",
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        16 => cfft_16(input),
+        #[cfg(any(
+            feature = "maxn-32",
+            feature = "maxn-64",
+            feature = "maxn-128",
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        32 => cfft_32(input),
+        #[cfg(any(
+            feature = "maxn-64",
+            feature = "maxn-128",
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        64 => cfft_64(input),
+        #[cfg(any(
+            feature = "maxn-128",
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        128 => cfft_128(input),
+        #[cfg(any(
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        256 => cfft_256(input),
+        #[cfg(any(
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        512 => cfft_512(input),
+        #[cfg(any(
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        1024 => cfft_1024(input),
+        #[cfg(any(
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        2048 => cfft_2048(input),
+        #[cfg(any(
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        4096 => cfft_4096(input),
+        #[cfg(any(
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        8192 => cfft_8192(input),
+        #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+        16384 => cfft_16384(input),
+        #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+        32768 => cfft_32768(input),
+        #[cfg(feature = "maxn-65536")]
+        65536 => cfft_65536(input),
+        n => return Err(FftError::for_size(n)),
+    };
+    Ok(result)
+}
+
+/// Perform an in-place mixed-radix CFFT.
+///
+/// The FFT size is determined by the length of the input slice. It must be of
+/// the form `2^a * 3^b * 5^c`, with the power-of-two factor `2^a` not
+/// exceeding the size selected by the `maxn-*` features. This allows
+/// transforming common audio frame sizes like 480, 960 or 1920.
+///
+/// The radix-3 and radix-5 stages compute their twiddle factors at runtime,
+/// which makes this function slower than the power-of-two transforms.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::cfft_mixed, Complex32, FftError};
+///
+/// let mut input = [Complex32::default(); 480];
+/// let result = cfft_mixed(&mut input);
+/// assert!(result.is_ok());
+///
+/// let mut input = [Complex32::default(); 14];
+/// let result = cfft_mixed(&mut input);
+/// assert_eq!(result, Err(FftError::UnsupportedFactor));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the length of `input` is not a supported FFT size.
+pub fn cfft_mixed(input: &mut [Complex32]) -> Result<&mut [Complex32], FftError> {
+    mixed::transform(input)?;
+    Ok(input)
+}
+
+/// Perform an in-place inverse mixed-radix CFFT.
+///
+/// The supported input sizes are the same as for [`cfft_mixed`].
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_mixed, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 480];
+/// let result = icfft_mixed(&mut input, Scaling::Normalized);
+/// assert!(result.is_ok());
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the length of `input` is not a supported FFT size.
+///
+/// [`cfft_mixed`]: fn.cfft_mixed.html
+pub fn icfft_mixed(
+    input: &mut [Complex32],
+    scaling: Scaling,
+) -> Result<&mut [Complex32], FftError> {
+    mixed::inverse_transform(input, scaling)?;
+    Ok(input)
+}
+
+/// Perform an in-place CFFT on an input of arbitrary size.
+///
+/// The FFT size is determined by the length of the input slice. The DFT is
+/// computed using Bluestein's algorithm, which expresses it as a convolution
+/// that is evaluated with power-of-two FFTs. These operate on the `scratch`
+/// buffer, which must hold at least [`cfft_any_scratch_len`] values. The
+/// power-of-two FFT size, which is roughly twice the input size, must not
+/// exceed the size selected by the `maxn-*` features.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{
+///     complex::{cfft_any, cfft_any_scratch_len},
+///     Complex32,
+/// };
+///
+/// const N: usize = 97;
+///
+/// let mut input = [Complex32::default(); N];
+/// let mut scratch = [Complex32::default(); cfft_any_scratch_len(N)];
+/// let result = cfft_any(&mut input, &mut scratch);
+/// assert!(result.is_ok());
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the length of `input` is not a supported FFT size, or
+/// if `scratch` is too small.
+///
+/// [`cfft_any_scratch_len`]: fn.cfft_any_scratch_len.html
+pub fn cfft_any<'a>(
+    input: &'a mut [Complex32],
+    scratch: &mut [Complex32],
+) -> Result<&'a mut [Complex32], FftError> {
+    bluestein::transform(input, scratch)?;
+    Ok(input)
+}
+
+/// Return the minimum scratch buffer length required by [`cfft_any`] to
+/// transform an input of size `n`.
+///
+/// [`cfft_any`]: fn.cfft_any.html
+pub const fn cfft_any_scratch_len(n: usize) -> usize {
+    2 * bluestein::fft_len(n)
+}
+
 /// Perform an in-place 2-point CFFT.
 ///
 /// # Example
@@ -68,6 +562,10 @@ pub fn cfft_4(input: &mut [Complex32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn cfft_8(input: &mut [Complex32]) -> &mut [Complex32] {
@@ -99,6 +597,10 @@ pub fn cfft_8(input: &mut [Complex32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn cfft_16(input: &mut [Complex32]) -> &mut [Complex32] {
@@ -129,6 +631,10 @@ pub fn cfft_16(input: &mut [Complex32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn cfft_32(input: &mut [Complex32]) -> &mut [Complex32] {
@@ -158,6 +664,10 @@ pub fn cfft_32(input: &mut [Complex32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn cfft_64(input: &mut [Complex32]) -> &mut [Complex32] {
@@ -186,6 +696,10 @@ pub fn cfft_64(input: &mut [Complex32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn cfft_128(input: &mut [Complex32]) -> &mut [Complex32] {
@@ -213,6 +727,10 @@ pub fn cfft_128(input: &mut [Complex32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn cfft_256(input: &mut [Complex32]) -> &mut [Complex32] {
@@ -239,6 +757,10 @@ pub fn cfft_256(input: &mut [Complex32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn cfft_512(input: &mut [Complex32]) -> &mut [Complex32] {
@@ -260,7 +782,15 @@ pub fn cfft_512(input: &mut [Complex32]) -> &mut [Complex32] {
 /// # Panics
 ///
 /// Panics if `input` has a length other than `1024`.
-#[cfg(any(feature = "maxn-1024", feature = "maxn-2048", feature = "maxn-4096"))]
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
 #[inline]
 pub fn cfft_1024(input: &mut [Complex32]) -> &mut [Complex32] {
     assert_eq!(input.len(), 1024);
@@ -281,7 +811,14 @@ pub fn cfft_1024(input: &mut [Complex32]) -> &mut [Complex32] {
 /// # Panics
 ///
 /// Panics if `input` has a length other than `2048`.
-#[cfg(any(feature = "maxn-2048", feature = "maxn-4096"))]
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
 #[inline]
 pub fn cfft_2048(input: &mut [Complex32]) -> &mut [Complex32] {
     assert_eq!(input.len(), 2048);
@@ -302,9 +839,548 @@ pub fn cfft_2048(input: &mut [Complex32]) -> &mut [Complex32] {
 /// # Panics
 ///
 /// Panics if `input` has a length other than `4096`.
-#[cfg(any(feature = "maxn-4096"))]
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
 #[inline]
 pub fn cfft_4096(input: &mut [Complex32]) -> &mut [Complex32] {
     assert_eq!(input.len(), 4096);
     CFftN4096::transform(input)
 }
+
+/// Perform an in-place 8192-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, complex::cfft_8192};
+///
+/// let mut input = [Complex32::default(); 8192];
+/// let result = cfft_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_8192(input: &mut [Complex32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 8192);
+    CFftN8192::transform(input)
+}
+
+/// Perform an in-place 16384-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, complex::cfft_16384};
+///
+/// let mut input = [Complex32::default(); 16384];
+/// let result = cfft_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn cfft_16384(input: &mut [Complex32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 16384);
+    CFftN16384::transform(input)
+}
+
+/// Perform an in-place 32768-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, complex::cfft_32768};
+///
+/// let mut input = [Complex32::default(); 32768];
+/// let result = cfft_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn cfft_32768(input: &mut [Complex32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 32768);
+    CFftN32768::transform(input)
+}
+
+/// Perform an in-place 65536-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{Complex32, complex::cfft_65536};
+///
+/// let mut input = [Complex32::default(); 65536];
+/// let result = cfft_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn cfft_65536(input: &mut [Complex32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 65536);
+    CFftN65536::transform(input)
+}
+
+/// Perform an in-place 2-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_2, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 2];
+/// let result = icfft_2(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn icfft_2(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 2);
+    CFftN2::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 4-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_4, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 4];
+/// let result = icfft_4(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn icfft_4(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 4);
+    CFftN4::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 8-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_8, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 8];
+/// let result = icfft_8(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_8(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 8);
+    CFftN8::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 16-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_16, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 16];
+/// let result = icfft_16(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_16(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 16);
+    CFftN16::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 32-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_32, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 32];
+/// let result = icfft_32(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_32(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 32);
+    CFftN32::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 64-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_64, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 64];
+/// let result = icfft_64(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_64(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 64);
+    CFftN64::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 128-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_128, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 128];
+/// let result = icfft_128(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_128(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 128);
+    CFftN128::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 256-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_256, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 256];
+/// let result = icfft_256(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_256(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 256);
+    CFftN256::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 512-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_512, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 512];
+/// let result = icfft_512(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_512(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 512);
+    CFftN512::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 1024-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_1024, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 1024];
+/// let result = icfft_1024(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_1024(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 1024);
+    CFftN1024::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 2048-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_2048, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 2048];
+/// let result = icfft_2048(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_2048(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 2048);
+    CFftN2048::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 4096-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_4096, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 4096];
+/// let result = icfft_4096(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_4096(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 4096);
+    CFftN4096::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 8192-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_8192, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 8192];
+/// let result = icfft_8192(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_8192(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 8192);
+    CFftN8192::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 16384-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_16384, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 16384];
+/// let result = icfft_16384(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn icfft_16384(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 16384);
+    CFftN16384::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 32768-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_32768, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 32768];
+/// let result = icfft_32768(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn icfft_32768(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 32768);
+    CFftN32768::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 65536-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex::icfft_65536, Complex32, Scaling};
+///
+/// let mut input = [Complex32::default(); 65536];
+/// let result = icfft_65536(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn icfft_65536(input: &mut [Complex32], scaling: Scaling) -> &mut [Complex32] {
+    assert_eq!(input.len(), 65536);
+    CFftN65536::inverse_transform(input, scaling)
+}
@@ -0,0 +1,900 @@
+//! FFT on double-precision complex inputs (CFFT)
+//!
+//! This module provides the same forward (`cfft_*`) and inverse (`icfft_*`)
+//! transforms as the [`complex`] module, operating on [`Complex64`] values
+//! instead. It is only available if the `f64` feature is enabled, which also
+//! adds a double-precision sine table.
+//!
+//! [`complex`]: ../complex/index.html
+//! [`Complex64`]: ../type.Complex64.html
+
+use crate::{cfft::*, Scaling};
+use num_complex::Complex64;
+
+/// Perform an in-place 2-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_2, Complex64};
+///
+/// let mut input = [Complex64::default(); 2];
+/// let result = cfft_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn cfft_2(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 2);
+    CFftN2::transform(input)
+}
+
+/// Perform an in-place 4-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_4, Complex64};
+///
+/// let mut input = [Complex64::default(); 4];
+/// let result = cfft_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn cfft_4(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 4);
+    CFftN4::transform(input)
+}
+
+/// Perform an in-place 8-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_8, Complex64};
+///
+/// let mut input = [Complex64::default(); 8];
+/// let result = cfft_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_8(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 8);
+    CFftN8::transform(input)
+}
+
+/// Perform an in-place 16-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_16, Complex64};
+///
+/// let mut input = [Complex64::default(); 16];
+/// let result = cfft_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_16(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 16);
+    CFftN16::transform(input)
+}
+
+/// Perform an in-place 32-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_32, Complex64};
+///
+/// let mut input = [Complex64::default(); 32];
+/// let result = cfft_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_32(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 32);
+    CFftN32::transform(input)
+}
+
+/// Perform an in-place 64-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_64, Complex64};
+///
+/// let mut input = [Complex64::default(); 64];
+/// let result = cfft_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_64(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 64);
+    CFftN64::transform(input)
+}
+
+/// Perform an in-place 128-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_128, Complex64};
+///
+/// let mut input = [Complex64::default(); 128];
+/// let result = cfft_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_128(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 128);
+    CFftN128::transform(input)
+}
+
+/// Perform an in-place 256-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_256, Complex64};
+///
+/// let mut input = [Complex64::default(); 256];
+/// let result = cfft_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_256(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 256);
+    CFftN256::transform(input)
+}
+
+/// Perform an in-place 512-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_512, Complex64};
+///
+/// let mut input = [Complex64::default(); 512];
+/// let result = cfft_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_512(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 512);
+    CFftN512::transform(input)
+}
+
+/// Perform an in-place 1024-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_1024, Complex64};
+///
+/// let mut input = [Complex64::default(); 1024];
+/// let result = cfft_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_1024(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 1024);
+    CFftN1024::transform(input)
+}
+
+/// Perform an in-place 2048-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_2048, Complex64};
+///
+/// let mut input = [Complex64::default(); 2048];
+/// let result = cfft_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_2048(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 2048);
+    CFftN2048::transform(input)
+}
+
+/// Perform an in-place 4096-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_4096, Complex64};
+///
+/// let mut input = [Complex64::default(); 4096];
+/// let result = cfft_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_4096(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 4096);
+    CFftN4096::transform(input)
+}
+
+/// Perform an in-place 8192-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_8192, Complex64};
+///
+/// let mut input = [Complex64::default(); 8192];
+/// let result = cfft_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_8192(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 8192);
+    CFftN8192::transform(input)
+}
+
+/// Perform an in-place 16384-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_16384, Complex64};
+///
+/// let mut input = [Complex64::default(); 16384];
+/// let result = cfft_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn cfft_16384(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 16384);
+    CFftN16384::transform(input)
+}
+
+/// Perform an in-place 32768-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_32768, Complex64};
+///
+/// let mut input = [Complex64::default(); 32768];
+/// let result = cfft_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn cfft_32768(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 32768);
+    CFftN32768::transform(input)
+}
+
+/// Perform an in-place 65536-point CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::cfft_65536, Complex64};
+///
+/// let mut input = [Complex64::default(); 65536];
+/// let result = cfft_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn cfft_65536(input: &mut [Complex64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 65536);
+    CFftN65536::transform(input)
+}
+
+/// Perform an in-place 2-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_2, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 2];
+/// let result = icfft_2(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn icfft_2(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 2);
+    CFftN2::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 4-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_4, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 4];
+/// let result = icfft_4(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn icfft_4(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 4);
+    CFftN4::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 8-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_8, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 8];
+/// let result = icfft_8(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_8(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 8);
+    CFftN8::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 16-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_16, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 16];
+/// let result = icfft_16(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_16(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 16);
+    CFftN16::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 32-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_32, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 32];
+/// let result = icfft_32(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_32(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 32);
+    CFftN32::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 64-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_64, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 64];
+/// let result = icfft_64(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_64(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 64);
+    CFftN64::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 128-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_128, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 128];
+/// let result = icfft_128(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_128(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 128);
+    CFftN128::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 256-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_256, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 256];
+/// let result = icfft_256(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_256(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 256);
+    CFftN256::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 512-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_512, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 512];
+/// let result = icfft_512(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_512(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 512);
+    CFftN512::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 1024-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_1024, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 1024];
+/// let result = icfft_1024(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_1024(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 1024);
+    CFftN1024::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 2048-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_2048, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 2048];
+/// let result = icfft_2048(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_2048(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 2048);
+    CFftN2048::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 4096-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_4096, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 4096];
+/// let result = icfft_4096(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_4096(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 4096);
+    CFftN4096::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 8192-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_8192, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 8192];
+/// let result = icfft_8192(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn icfft_8192(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 8192);
+    CFftN8192::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 16384-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_16384, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 16384];
+/// let result = icfft_16384(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn icfft_16384(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 16384);
+    CFftN16384::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 32768-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_32768, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 32768];
+/// let result = icfft_32768(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn icfft_32768(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 32768);
+    CFftN32768::inverse_transform(input, scaling)
+}
+
+/// Perform an in-place 65536-point inverse CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex64::icfft_65536, Complex64, Scaling};
+///
+/// let mut input = [Complex64::default(); 65536];
+/// let result = icfft_65536(&mut input, Scaling::Normalized);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn icfft_65536(input: &mut [Complex64], scaling: Scaling) -> &mut [Complex64] {
+    assert_eq!(input.len(), 65536);
+    CFftN65536::inverse_transform(input, scaling)
+}
@@ -0,0 +1,460 @@
+//! FFT on Q15 fixed-point complex inputs (CFFT)
+//!
+//! This module provides forward transforms on complex values in the Q15
+//! fixed-point format, for targets without a floating-point unit. Real and
+//! imaginary parts are stored as `i16` values, representing numbers in the
+//! range `[-1, 1)`.
+//!
+//! To prevent overflows, the output of each butterfly stage is halved. The
+//! resulting spectrum is therefore scaled by `1/N` compared to the output of
+//! the corresponding floating-point CFFT. The magnitudes of the input values
+//! should not exceed `1`, otherwise results may saturate.
+//!
+//! This module is only available if the `q15` feature is enabled, which also
+//! adds a Q15 sine table.
+
+use crate::{cfft::*, ComplexQ15};
+
+/// Perform an in-place 2-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_2, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 2];
+/// let result = cfft_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn cfft_2(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 2);
+    CFftN2::transform_fixed(input)
+}
+
+/// Perform an in-place 4-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_4, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 4];
+/// let result = cfft_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn cfft_4(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 4);
+    CFftN4::transform_fixed(input)
+}
+
+/// Perform an in-place 8-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_8, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 8];
+/// let result = cfft_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_8(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 8);
+    CFftN8::transform_fixed(input)
+}
+
+/// Perform an in-place 16-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_16, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 16];
+/// let result = cfft_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_16(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 16);
+    CFftN16::transform_fixed(input)
+}
+
+/// Perform an in-place 32-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_32, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 32];
+/// let result = cfft_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_32(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 32);
+    CFftN32::transform_fixed(input)
+}
+
+/// Perform an in-place 64-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_64, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 64];
+/// let result = cfft_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_64(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 64);
+    CFftN64::transform_fixed(input)
+}
+
+/// Perform an in-place 128-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_128, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 128];
+/// let result = cfft_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_128(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 128);
+    CFftN128::transform_fixed(input)
+}
+
+/// Perform an in-place 256-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_256, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 256];
+/// let result = cfft_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_256(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 256);
+    CFftN256::transform_fixed(input)
+}
+
+/// Perform an in-place 512-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_512, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 512];
+/// let result = cfft_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_512(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 512);
+    CFftN512::transform_fixed(input)
+}
+
+/// Perform an in-place 1024-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_1024, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 1024];
+/// let result = cfft_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_1024(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 1024);
+    CFftN1024::transform_fixed(input)
+}
+
+/// Perform an in-place 2048-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_2048, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 2048];
+/// let result = cfft_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_2048(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 2048);
+    CFftN2048::transform_fixed(input)
+}
+
+/// Perform an in-place 4096-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_4096, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 4096];
+/// let result = cfft_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_4096(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 4096);
+    CFftN4096::transform_fixed(input)
+}
+
+/// Perform an in-place 8192-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_8192, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 8192];
+/// let result = cfft_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn cfft_8192(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 8192);
+    CFftN8192::transform_fixed(input)
+}
+
+/// Perform an in-place 16384-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_16384, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 16384];
+/// let result = cfft_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn cfft_16384(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 16384);
+    CFftN16384::transform_fixed(input)
+}
+
+/// Perform an in-place 32768-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_32768, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 32768];
+/// let result = cfft_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn cfft_32768(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 32768);
+    CFftN32768::transform_fixed(input)
+}
+
+/// Perform an in-place 65536-point Q15 CFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{complex_q15::cfft_65536, ComplexQ15};
+///
+/// let mut input = [ComplexQ15::default(); 65536];
+/// let result = cfft_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn cfft_65536(input: &mut [ComplexQ15]) -> &mut [ComplexQ15] {
+    assert_eq!(input.len(), 65536);
+    CFftN65536::transform_fixed(input)
+}
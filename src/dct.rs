@@ -0,0 +1,1486 @@
+//! Discrete cosine transforms (DCT)
+//!
+//! The DCT-II (`dct2_*`) of `N` real values is computed in-place using the
+//! algorithm by Makhoul: The input is reordered so that its even-indexed
+//! values are followed by its odd-indexed values in reverse order, an
+//! `N`-point packed RFFT is computed and its output is rotated by the
+//! twiddle factors `e^(-πi * k / 2N)`. Apart from the RFFT, this requires a
+//! single pass over the data.
+//!
+//! The DCT-II is defined without any normalization:
+//!
+//! ```text
+//! X[k] = sum(x[n] * cos(π * (2n + 1) * k / 2N) for n in 0..N)
+//! ```
+//!
+//! The DCT-III (`dct3_*`) is its inverse. Its output is scaled by `2/N` and
+//! the DC term is weighted by `1/2`, so a DCT-III exactly undoes the
+//! corresponding DCT-II:
+//!
+//! ```text
+//! x[n] = 2/N * (X[0] / 2 + sum(X[k] * cos(π * (2n + 1) * k / 2N) for k in 1..N))
+//! ```
+//!
+//! The DCT-IV (`dct4_*`) is computed using an `N/2`-point CFFT, framed by
+//! two passes applying twiddle factors. It is defined without normalization
+//! as well and is its own inverse, up to a factor of `N/2`:
+//!
+//! ```text
+//! X[k] = sum(x[n] * cos(π * (2n + 1) * (2k + 1) / 4N) for n in 0..N)
+//! ```
+//!
+//! The twiddle factors are looked up in microfft's sine table. For sizes
+//! above a quarter (DCT-II and DCT-III) or an eighth (DCT-IV) of the maximum
+//! size selected by the `maxn-*` feature, the
+//! table lookups are refined by a small correction computed at runtime.
+
+use crate::{cfft::*, math, permute::permute, rfft::*};
+use core::f32::consts::FRAC_1_SQRT_2;
+use num_complex::Complex32;
+
+#[inline]
+pub(crate) fn dct2<R: RFft>(x: &mut [f32]) -> &mut [f32] {
+    let n = R::N;
+    debug_assert_eq!(x.len(), n);
+
+    // even values in order, followed by the odd values in reverse order
+    permute(x, |i| if i % 2 == 0 { i / 2 } else { n - 1 - i / 2 });
+
+    let y = R::transform_packed(x);
+
+    // DC and Nyquist
+    y[0] = Complex32::new(y[0].re, y[0].im * FRAC_1_SQRT_2);
+
+    // `X[k]` and `X[N - k]` are the real and negated imaginary part of the
+    // rotated spectrum value
+    for (k, y_k) in y.iter_mut().enumerate().skip(1) {
+        let z = *y_k * fine_twiddle(k, 4 * n);
+        *y_k = Complex32::new(z.re, -z.im);
+    }
+
+    let x = as_real(y);
+    permute(x, |i| match i {
+        0 => 0,
+        1 => n / 2,
+        i if i % 2 == 0 => i / 2,
+        i => n - i / 2,
+    });
+    x
+}
+
+#[inline]
+fn dct3<R: RFft>(x: &mut [f32]) -> &mut [f32] {
+    let n = R::N;
+    debug_assert_eq!(x.len(), n);
+
+    // store `X[k]` and `X[N - k]` next to each other, undoing the final
+    // reordering of the DCT-II
+    permute(x, |i| match i {
+        0 => 0,
+        i if i == n / 2 => 1,
+        i if i < n / 2 => 2 * i,
+        i => 2 * (n - i) + 1,
+    });
+
+    let y = as_complex(x);
+
+    // DC and Nyquist
+    y[0] = Complex32::new(y[0].re, y[0].im * 2. * FRAC_1_SQRT_2);
+
+    for (k, y_k) in y.iter_mut().enumerate().skip(1) {
+        *y_k = y_k.conj() * fine_twiddle(k, 4 * n).conj();
+    }
+
+    let x = R::inverse_transform(y);
+
+    // undo the even/odd reordering of the DCT-II
+    permute(x, |i| {
+        if i < n / 2 {
+            2 * i
+        } else {
+            2 * (n - 1 - i) + 1
+        }
+    });
+    x
+}
+
+#[inline]
+pub(crate) fn dct4<C: CFft>(x: &mut [f32]) -> &mut [f32] {
+    let n = C::N * 2;
+    debug_assert_eq!(x.len(), n);
+
+    // pair `x[2m]` with `x[N - 1 - 2m]` by reversing the odd values
+    swap_odd(x);
+
+    let y = as_complex(x);
+    for (m, y_m) in y.iter_mut().enumerate() {
+        *y_m *= fine_twiddle(4 * m + 1, 8 * n);
+    }
+
+    C::transform(y);
+
+    // `X[2k]` and `X[N - 1 - 2k]` are the real and negated imaginary part of
+    // the rotated spectrum value
+    for (k, y_k) in y.iter_mut().enumerate() {
+        let z = *y_k * fine_twiddle(k, 2 * n);
+        *y_k = Complex32::new(z.re, -z.im);
+    }
+
+    let x = as_real(y);
+    swap_odd(x);
+    x
+}
+
+/// Reverse the order of the odd-indexed values.
+fn swap_odd(x: &mut [f32]) {
+    let n = x.len();
+    for i in (1..n / 2).step_by(2) {
+        x.swap(i, n - i);
+    }
+}
+
+/// Compute the twiddle factor `e^(-2πi * k / n)` for a power of two `n`,
+/// which may exceed `MAX_N`.
+pub(crate) fn fine_twiddle(k: usize, n: usize) -> Complex32 {
+    if n <= MAX_N {
+        return twiddle(k, n, false);
+    }
+
+    // the table resolution is too coarse, so rotate the closest table entry
+    // by the remaining angle
+    let step = n / MAX_N;
+    let (cos, sin) = math::cos_sin(k % step, n);
+    twiddle::<f32>(k / step, MAX_N, false) * Complex32::new(cos, -sin)
+}
+
+/// Perform an in-place 2-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_2;
+///
+/// let mut input = [0.; 2];
+/// let result = dct2_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn dct2_2(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2);
+    dct2::<RFftN2>(input)
+}
+
+/// Perform an in-place 4-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_4;
+///
+/// let mut input = [0.; 4];
+/// let result = dct2_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn dct2_4(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4);
+    dct2::<RFftN4>(input)
+}
+
+/// Perform an in-place 8-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_8;
+///
+/// let mut input = [0.; 8];
+/// let result = dct2_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_8(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8);
+    dct2::<RFftN8>(input)
+}
+
+/// Perform an in-place 16-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_16;
+///
+/// let mut input = [0.; 16];
+/// let result = dct2_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_16(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16);
+    dct2::<RFftN16>(input)
+}
+
+/// Perform an in-place 32-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_32;
+///
+/// let mut input = [0.; 32];
+/// let result = dct2_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_32(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32);
+    dct2::<RFftN32>(input)
+}
+
+/// Perform an in-place 64-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_64;
+///
+/// let mut input = [0.; 64];
+/// let result = dct2_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_64(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 64);
+    dct2::<RFftN64>(input)
+}
+
+/// Perform an in-place 128-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_128;
+///
+/// let mut input = [0.; 128];
+/// let result = dct2_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_128(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 128);
+    dct2::<RFftN128>(input)
+}
+
+/// Perform an in-place 256-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_256;
+///
+/// let mut input = [0.; 256];
+/// let result = dct2_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_256(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 256);
+    dct2::<RFftN256>(input)
+}
+
+/// Perform an in-place 512-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_512;
+///
+/// let mut input = [0.; 512];
+/// let result = dct2_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_512(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 512);
+    dct2::<RFftN512>(input)
+}
+
+/// Perform an in-place 1024-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_1024;
+///
+/// let mut input = [0.; 1024];
+/// let result = dct2_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_1024(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 1024);
+    dct2::<RFftN1024>(input)
+}
+
+/// Perform an in-place 2048-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_2048;
+///
+/// let mut input = [0.; 2048];
+/// let result = dct2_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_2048(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2048);
+    dct2::<RFftN2048>(input)
+}
+
+/// Perform an in-place 4096-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_4096;
+///
+/// let mut input = [0.; 4096];
+/// let result = dct2_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_4096(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4096);
+    dct2::<RFftN4096>(input)
+}
+
+/// Perform an in-place 8192-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = dct2_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct2_8192(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8192);
+    dct2::<RFftN8192>(input)
+}
+
+/// Perform an in-place 16384-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = dct2_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dct2_16384(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16384);
+    dct2::<RFftN16384>(input)
+}
+
+/// Perform an in-place 32768-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = dct2_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dct2_32768(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32768);
+    dct2::<RFftN32768>(input)
+}
+
+/// Perform an in-place 65536-point DCT-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct2_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = dct2_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn dct2_65536(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 65536);
+    dct2::<RFftN65536>(input)
+}
+
+/// Perform an in-place 2-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_2;
+///
+/// let mut input = [0.; 2];
+/// let result = dct3_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn dct3_2(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2);
+    dct3::<RFftN2>(input)
+}
+
+/// Perform an in-place 4-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_4;
+///
+/// let mut input = [0.; 4];
+/// let result = dct3_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn dct3_4(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4);
+    dct3::<RFftN4>(input)
+}
+
+/// Perform an in-place 8-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_8;
+///
+/// let mut input = [0.; 8];
+/// let result = dct3_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_8(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8);
+    dct3::<RFftN8>(input)
+}
+
+/// Perform an in-place 16-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_16;
+///
+/// let mut input = [0.; 16];
+/// let result = dct3_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_16(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16);
+    dct3::<RFftN16>(input)
+}
+
+/// Perform an in-place 32-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_32;
+///
+/// let mut input = [0.; 32];
+/// let result = dct3_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_32(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32);
+    dct3::<RFftN32>(input)
+}
+
+/// Perform an in-place 64-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_64;
+///
+/// let mut input = [0.; 64];
+/// let result = dct3_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_64(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 64);
+    dct3::<RFftN64>(input)
+}
+
+/// Perform an in-place 128-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_128;
+///
+/// let mut input = [0.; 128];
+/// let result = dct3_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_128(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 128);
+    dct3::<RFftN128>(input)
+}
+
+/// Perform an in-place 256-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_256;
+///
+/// let mut input = [0.; 256];
+/// let result = dct3_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_256(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 256);
+    dct3::<RFftN256>(input)
+}
+
+/// Perform an in-place 512-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_512;
+///
+/// let mut input = [0.; 512];
+/// let result = dct3_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_512(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 512);
+    dct3::<RFftN512>(input)
+}
+
+/// Perform an in-place 1024-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_1024;
+///
+/// let mut input = [0.; 1024];
+/// let result = dct3_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_1024(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 1024);
+    dct3::<RFftN1024>(input)
+}
+
+/// Perform an in-place 2048-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_2048;
+///
+/// let mut input = [0.; 2048];
+/// let result = dct3_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_2048(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2048);
+    dct3::<RFftN2048>(input)
+}
+
+/// Perform an in-place 4096-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_4096;
+///
+/// let mut input = [0.; 4096];
+/// let result = dct3_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_4096(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4096);
+    dct3::<RFftN4096>(input)
+}
+
+/// Perform an in-place 8192-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = dct3_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct3_8192(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8192);
+    dct3::<RFftN8192>(input)
+}
+
+/// Perform an in-place 16384-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = dct3_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dct3_16384(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16384);
+    dct3::<RFftN16384>(input)
+}
+
+/// Perform an in-place 32768-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = dct3_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dct3_32768(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32768);
+    dct3::<RFftN32768>(input)
+}
+
+/// Perform an in-place 65536-point DCT-III.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct3_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = dct3_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn dct3_65536(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 65536);
+    dct3::<RFftN65536>(input)
+}
+
+/// Perform an in-place 2-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_2;
+///
+/// let mut input = [0.; 2];
+/// let result = dct4_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn dct4_2(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2);
+    dct4::<CFftN1>(input)
+}
+
+/// Perform an in-place 4-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_4;
+///
+/// let mut input = [0.; 4];
+/// let result = dct4_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn dct4_4(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4);
+    dct4::<CFftN2>(input)
+}
+
+/// Perform an in-place 8-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_8;
+///
+/// let mut input = [0.; 8];
+/// let result = dct4_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_8(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8);
+    dct4::<CFftN4>(input)
+}
+
+/// Perform an in-place 16-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_16;
+///
+/// let mut input = [0.; 16];
+/// let result = dct4_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_16(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16);
+    dct4::<CFftN8>(input)
+}
+
+/// Perform an in-place 32-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_32;
+///
+/// let mut input = [0.; 32];
+/// let result = dct4_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_32(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32);
+    dct4::<CFftN16>(input)
+}
+
+/// Perform an in-place 64-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_64;
+///
+/// let mut input = [0.; 64];
+/// let result = dct4_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_64(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 64);
+    dct4::<CFftN32>(input)
+}
+
+/// Perform an in-place 128-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_128;
+///
+/// let mut input = [0.; 128];
+/// let result = dct4_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_128(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 128);
+    dct4::<CFftN64>(input)
+}
+
+/// Perform an in-place 256-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_256;
+///
+/// let mut input = [0.; 256];
+/// let result = dct4_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_256(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 256);
+    dct4::<CFftN128>(input)
+}
+
+/// Perform an in-place 512-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_512;
+///
+/// let mut input = [0.; 512];
+/// let result = dct4_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_512(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 512);
+    dct4::<CFftN256>(input)
+}
+
+/// Perform an in-place 1024-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_1024;
+///
+/// let mut input = [0.; 1024];
+/// let result = dct4_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_1024(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 1024);
+    dct4::<CFftN512>(input)
+}
+
+/// Perform an in-place 2048-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_2048;
+///
+/// let mut input = [0.; 2048];
+/// let result = dct4_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_2048(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2048);
+    dct4::<CFftN1024>(input)
+}
+
+/// Perform an in-place 4096-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_4096;
+///
+/// let mut input = [0.; 4096];
+/// let result = dct4_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_4096(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4096);
+    dct4::<CFftN2048>(input)
+}
+
+/// Perform an in-place 8192-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = dct4_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dct4_8192(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8192);
+    dct4::<CFftN4096>(input)
+}
+
+/// Perform an in-place 16384-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = dct4_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dct4_16384(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16384);
+    dct4::<CFftN8192>(input)
+}
+
+/// Perform an in-place 32768-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = dct4_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dct4_32768(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32768);
+    dct4::<CFftN16384>(input)
+}
+
+/// Perform an in-place 65536-point DCT-IV.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dct::dct4_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = dct4_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn dct4_65536(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 65536);
+    dct4::<CFftN32768>(input)
+}
@@ -0,0 +1,996 @@
+//! Discrete sine transforms (DST)
+//!
+//! The DST-I (`dst1_*`) expands a sequence that is zero at both boundaries
+//! into a sine series. The `N`-point DST-I operates on `N - 1` values, which
+//! are stored at the indices `1..N` of the input, while the value at index
+//! `0` is the boundary value and ignored. It is defined as
+//!
+//! ```text
+//! X[k] = sum(x[n] * sin(π * n * k / N) for n in 1..N)
+//! ```
+//!
+//! and stored in the same layout, with `X[0]` set to zero. The DST-I is its
+//! own inverse, up to a factor of `N/2`. It is computed in-place by folding
+//! the input into a real sequence of `N` values, computing its `N`-point
+//! packed RFFT and assembling the output from the real and imaginary parts of
+//! the spectrum. Since the odd-indexed output values are computed as
+//! cumulative sums, their rounding errors grow with `N`.
+//!
+//! The DST-II (`dst2_*`) of `N` values is defined as
+//!
+//! ```text
+//! X[k] = sum(x[n] * sin(π * (2n + 1) * (k + 1) / 2N) for n in 0..N)
+//! ```
+//!
+//! It is computed using the DCT-II, by negating the odd-indexed input values
+//! and reversing the order of the output values.
+//!
+//! Both transforms are defined without normalization and look up their
+//! twiddle factors in microfft's sine table.
+
+use crate::{
+    dct::{dct2, fine_twiddle},
+    rfft::*,
+};
+use num_complex::Complex32;
+
+#[inline]
+fn dst1<R: RFft>(x: &mut [f32]) -> &mut [f32] {
+    let n = R::N;
+    debug_assert_eq!(x.len(), n);
+
+    // fold the input into `sin(π * j / N) * (x[j] + x[N - j])` plus the
+    // antisymmetric part `(x[j] - x[N - j]) / 2`
+    x[0] = 0.;
+    for j in 1..=n / 2 {
+        let sin = -fine_twiddle(j, 2 * n).im;
+        let (a, b) = (x[j], x[n - j]);
+        let (sum, diff) = (sin * (a + b), (a - b) / 2.);
+        x[j] = sum + diff;
+        x[n - j] = sum - diff;
+    }
+
+    let y = R::transform_packed(x);
+
+    // the even terms are the negated imaginary parts of the spectrum, the odd
+    // terms are the cumulative sums of its real parts
+    let mut sum = y[0].re / 2.;
+    y[0] = Complex32::new(0., sum);
+    for y_k in y.iter_mut().skip(1) {
+        sum += y_k.re;
+        *y_k = Complex32::new(-y_k.im, sum);
+    }
+
+    as_real(y)
+}
+
+#[inline]
+fn dst2<R: RFft>(x: &mut [f32]) -> &mut [f32] {
+    for value in x.iter_mut().skip(1).step_by(2) {
+        *value = -*value;
+    }
+
+    let x = dct2::<R>(x);
+    x.reverse();
+    x
+}
+
+/// Perform an in-place 2-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_2;
+///
+/// let mut input = [0.; 2];
+/// let result = dst1_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn dst1_2(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2);
+    dst1::<RFftN2>(input)
+}
+
+/// Perform an in-place 4-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_4;
+///
+/// let mut input = [0.; 4];
+/// let result = dst1_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn dst1_4(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4);
+    dst1::<RFftN4>(input)
+}
+
+/// Perform an in-place 8-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_8;
+///
+/// let mut input = [0.; 8];
+/// let result = dst1_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_8(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8);
+    dst1::<RFftN8>(input)
+}
+
+/// Perform an in-place 16-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_16;
+///
+/// let mut input = [0.; 16];
+/// let result = dst1_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_16(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16);
+    dst1::<RFftN16>(input)
+}
+
+/// Perform an in-place 32-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_32;
+///
+/// let mut input = [0.; 32];
+/// let result = dst1_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_32(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32);
+    dst1::<RFftN32>(input)
+}
+
+/// Perform an in-place 64-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_64;
+///
+/// let mut input = [0.; 64];
+/// let result = dst1_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_64(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 64);
+    dst1::<RFftN64>(input)
+}
+
+/// Perform an in-place 128-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_128;
+///
+/// let mut input = [0.; 128];
+/// let result = dst1_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_128(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 128);
+    dst1::<RFftN128>(input)
+}
+
+/// Perform an in-place 256-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_256;
+///
+/// let mut input = [0.; 256];
+/// let result = dst1_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_256(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 256);
+    dst1::<RFftN256>(input)
+}
+
+/// Perform an in-place 512-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_512;
+///
+/// let mut input = [0.; 512];
+/// let result = dst1_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_512(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 512);
+    dst1::<RFftN512>(input)
+}
+
+/// Perform an in-place 1024-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_1024;
+///
+/// let mut input = [0.; 1024];
+/// let result = dst1_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_1024(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 1024);
+    dst1::<RFftN1024>(input)
+}
+
+/// Perform an in-place 2048-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_2048;
+///
+/// let mut input = [0.; 2048];
+/// let result = dst1_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_2048(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2048);
+    dst1::<RFftN2048>(input)
+}
+
+/// Perform an in-place 4096-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_4096;
+///
+/// let mut input = [0.; 4096];
+/// let result = dst1_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_4096(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4096);
+    dst1::<RFftN4096>(input)
+}
+
+/// Perform an in-place 8192-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = dst1_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst1_8192(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8192);
+    dst1::<RFftN8192>(input)
+}
+
+/// Perform an in-place 16384-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = dst1_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dst1_16384(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16384);
+    dst1::<RFftN16384>(input)
+}
+
+/// Perform an in-place 32768-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = dst1_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dst1_32768(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32768);
+    dst1::<RFftN32768>(input)
+}
+
+/// Perform an in-place 65536-point DST-I.
+///
+/// The value at index `0` is ignored and set to zero in the output.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst1_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = dst1_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn dst1_65536(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 65536);
+    dst1::<RFftN65536>(input)
+}
+
+/// Perform an in-place 2-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_2;
+///
+/// let mut input = [0.; 2];
+/// let result = dst2_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn dst2_2(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2);
+    dst2::<RFftN2>(input)
+}
+
+/// Perform an in-place 4-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_4;
+///
+/// let mut input = [0.; 4];
+/// let result = dst2_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn dst2_4(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4);
+    dst2::<RFftN4>(input)
+}
+
+/// Perform an in-place 8-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_8;
+///
+/// let mut input = [0.; 8];
+/// let result = dst2_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_8(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8);
+    dst2::<RFftN8>(input)
+}
+
+/// Perform an in-place 16-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_16;
+///
+/// let mut input = [0.; 16];
+/// let result = dst2_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_16(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16);
+    dst2::<RFftN16>(input)
+}
+
+/// Perform an in-place 32-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_32;
+///
+/// let mut input = [0.; 32];
+/// let result = dst2_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_32(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32);
+    dst2::<RFftN32>(input)
+}
+
+/// Perform an in-place 64-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_64;
+///
+/// let mut input = [0.; 64];
+/// let result = dst2_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_64(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 64);
+    dst2::<RFftN64>(input)
+}
+
+/// Perform an in-place 128-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_128;
+///
+/// let mut input = [0.; 128];
+/// let result = dst2_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_128(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 128);
+    dst2::<RFftN128>(input)
+}
+
+/// Perform an in-place 256-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_256;
+///
+/// let mut input = [0.; 256];
+/// let result = dst2_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_256(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 256);
+    dst2::<RFftN256>(input)
+}
+
+/// Perform an in-place 512-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_512;
+///
+/// let mut input = [0.; 512];
+/// let result = dst2_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_512(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 512);
+    dst2::<RFftN512>(input)
+}
+
+/// Perform an in-place 1024-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_1024;
+///
+/// let mut input = [0.; 1024];
+/// let result = dst2_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_1024(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 1024);
+    dst2::<RFftN1024>(input)
+}
+
+/// Perform an in-place 2048-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_2048;
+///
+/// let mut input = [0.; 2048];
+/// let result = dst2_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_2048(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2048);
+    dst2::<RFftN2048>(input)
+}
+
+/// Perform an in-place 4096-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_4096;
+///
+/// let mut input = [0.; 4096];
+/// let result = dst2_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_4096(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4096);
+    dst2::<RFftN4096>(input)
+}
+
+/// Perform an in-place 8192-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = dst2_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dst2_8192(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8192);
+    dst2::<RFftN8192>(input)
+}
+
+/// Perform an in-place 16384-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = dst2_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dst2_16384(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16384);
+    dst2::<RFftN16384>(input)
+}
+
+/// Perform an in-place 32768-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = dst2_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dst2_32768(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32768);
+    dst2::<RFftN32768>(input)
+}
+
+/// Perform an in-place 65536-point DST-II.
+///
+/// # Example
+///
+/// ```
+/// use microfft::dst::dst2_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = dst2_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn dst2_65536(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 65536);
+    dst2::<RFftN65536>(input)
+}
@@ -0,0 +1,102 @@
+use core::convert::TryFrom;
+use num_complex::Complex;
+use num_traits::Bounded;
+
+/// Fixed-point types the FFT algorithms can operate on.
+///
+/// All operations saturate instead of overflowing.
+pub(crate) trait Fixed: Copy + Default + 'static {
+    /// The sine table to look up twiddle factors in.
+    const SINE: &'static [Self];
+
+    fn neg(self) -> Self;
+
+    fn half(self) -> Self;
+
+    /// Compute `(a + b) / 2`.
+    fn half_add(a: Self, b: Self) -> Self;
+
+    /// Compute `(a - b) / 2`.
+    fn half_sub(a: Self, b: Self) -> Self;
+
+    /// Multiply a complex value with a twiddle factor, rounding to the
+    /// nearest result.
+    fn mul(a: Complex<Self>, twiddle: Complex<Self>) -> Complex<Self>;
+
+    #[inline]
+    fn half_sum(a: Complex<Self>, b: Complex<Self>) -> Complex<Self> {
+        Complex::new(Self::half_add(a.re, b.re), Self::half_add(a.im, b.im))
+    }
+
+    #[inline]
+    fn half_diff(a: Complex<Self>, b: Complex<Self>) -> Complex<Self> {
+        Complex::new(Self::half_sub(a.re, b.re), Self::half_sub(a.im, b.im))
+    }
+}
+
+macro_rules! fixed_impl {
+    ( $( #[cfg($cfg:meta)] $T:ty => ($Wide:ty, $frac_bits:expr, $sine:path), )* ) => {
+        $(
+            #[cfg($cfg)]
+            impl Fixed for $T {
+                const SINE: &'static [Self] = $sine;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    self.saturating_neg()
+                }
+
+                #[inline]
+                fn half(self) -> Self {
+                    self >> 1
+                }
+
+                #[inline]
+                fn half_add(a: Self, b: Self) -> Self {
+                    ((<$Wide>::from(a) + <$Wide>::from(b)) >> 1) as Self
+                }
+
+                #[inline]
+                fn half_sub(a: Self, b: Self) -> Self {
+                    ((<$Wide>::from(a) - <$Wide>::from(b)) >> 1) as Self
+                }
+
+                #[inline]
+                fn mul(a: Complex<Self>, twiddle: Complex<Self>) -> Complex<Self> {
+                    let (a_re, a_im) = (<$Wide>::from(a.re), <$Wide>::from(a.im));
+                    let (b_re, b_im) = (<$Wide>::from(twiddle.re), <$Wide>::from(twiddle.im));
+                    let round = 1 << ($frac_bits - 1);
+
+                    let re = (a_re * b_re - a_im * b_im + round) >> $frac_bits;
+                    let im = (a_re * b_im + a_im * b_re + round) >> $frac_bits;
+                    Complex::new(saturate(re), saturate(im))
+                }
+            }
+        )*
+    };
+}
+
+fixed_impl! {
+    #[cfg(feature = "q15")]
+    i16 => (i32, 15, crate::tables::SINE_Q15),
+    #[cfg(feature = "q31")]
+    i32 => (i64, 31, crate::tables::SINE_Q31),
+}
+
+/// Convert a wide intermediate result back into the fixed-point type,
+/// saturating on overflow.
+#[inline]
+fn saturate<W, T>(x: W) -> T
+where
+    W: PartialOrd + Default,
+    T: TryFrom<W> + Bounded,
+{
+    let negative = x < W::default();
+    T::try_from(x).unwrap_or_else(|_| {
+        if negative {
+            T::min_value()
+        } else {
+            T::max_value()
+        }
+    })
+}
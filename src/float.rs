@@ -0,0 +1,29 @@
+use crate::tables;
+use num_traits::{float::FloatCore, NumAssign};
+
+/// Floating-point types the FFT algorithms can operate on.
+pub(crate) trait Float: FloatCore + NumAssign + 'static {
+    /// The sine table to look up twiddle factors in.
+    const SINE: &'static [Self];
+
+    fn from_usize(n: usize) -> Self;
+}
+
+impl Float for f32 {
+    const SINE: &'static [Self] = tables::SINE;
+
+    #[inline]
+    fn from_usize(n: usize) -> Self {
+        n as Self
+    }
+}
+
+#[cfg(feature = "f64")]
+impl Float for f64 {
+    const SINE: &'static [Self] = tables::SINE_F64;
+
+    #[inline]
+    fn from_usize(n: usize) -> Self {
+        n as Self
+    }
+}
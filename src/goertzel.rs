@@ -0,0 +1,174 @@
+//! Goertzel algorithm for evaluating single DFT bins
+//!
+//! If only a few bins of a spectrum are of interest, like when detecting the
+//! presence of specific tones (e.g. DTMF), computing each of them using the
+//! Goertzel algorithm is cheaper than computing a full FFT.
+//!
+//! The [`Goertzel`] type processes samples one at a time, allowing bins to
+//! be evaluated on a stream of samples. The [`value`] and [`power`] functions
+//! evaluate a bin on a complete block of samples.
+//!
+//! If the bin lies on the grid of a power-of-two FFT supported by the
+//! selected `maxn-*` feature, its coefficients are looked up in microfft's
+//! sine table. Otherwise, they are computed at runtime.
+//!
+//! [`Goertzel`]: struct.Goertzel.html
+//! [`value`]: fn.value.html
+//! [`power`]: fn.power.html
+
+use crate::{
+    cfft::{twiddle, MAX_N},
+    math,
+};
+use num_complex::Complex32;
+
+/// Streaming single-bin DFT evaluation.
+///
+/// # Example
+///
+/// ```
+/// use microfft::goertzel::Goertzel;
+/// use std::f32::consts::PI;
+///
+/// // a sine wave at bin 5 of a 64-point DFT
+/// let samples = (0..64).map(|i| (2. * PI * 5. * i as f32 / 64.).sin());
+///
+/// let mut bin_5 = Goertzel::new(5, 64);
+/// let mut bin_9 = Goertzel::new(9, 64);
+/// for sample in samples {
+///     bin_5.push(sample);
+///     bin_9.push(sample);
+/// }
+///
+/// assert!(bin_5.power() > 1000.);
+/// assert!(bin_9.power() < 1e-3);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Goertzel {
+    cos: f32,
+    sin: f32,
+    s1: f32,
+    s2: f32,
+}
+
+impl Goertzel {
+    /// Create a detector for bin `k` of an `n`-point DFT.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    pub fn new(k: usize, n: usize) -> Self {
+        assert!(n > 0, "DFT size must not be zero");
+
+        let k = k % n;
+        let (cos, sin) = if n.is_power_of_two() && n <= MAX_N {
+            let w = twiddle::<f32>(k, n, false);
+            (w.re, -w.im)
+        } else {
+            math::cos_sin(k, n)
+        };
+        Self::with_coefficients(cos, sin)
+    }
+
+    /// Create a detector for the given frequency, in cycles per sample.
+    ///
+    /// The frequency does not need to lie on the grid of any DFT size.
+    pub fn with_frequency(freq: f32) -> Self {
+        let (cos, sin) = math::cos_sin_turns(f64::from(freq));
+        Self::with_coefficients(cos, sin)
+    }
+
+    fn with_coefficients(cos: f32, sin: f32) -> Self {
+        Self {
+            cos,
+            sin,
+            s1: 0.,
+            s2: 0.,
+        }
+    }
+
+    /// Process the next sample.
+    #[inline]
+    pub fn push(&mut self, sample: f32) {
+        let s0 = sample + 2. * self.cos * self.s1 - self.s2;
+        self.s2 = self.s1;
+        self.s1 = s0;
+    }
+
+    /// Process a block of samples.
+    pub fn extend(&mut self, samples: &[f32]) {
+        for sample in samples {
+            self.push(*sample);
+        }
+    }
+
+    /// Return the complex value of the bin.
+    ///
+    /// If exactly `n` samples were processed by a detector for bin `k` of an
+    /// `n`-point DFT, this is the value of that bin. In general, it is the DFT
+    /// of the processed samples, with the phase referring to the time of the
+    /// next sample.
+    pub fn value(&self) -> Complex32 {
+        Complex32::new(self.cos * self.s1 - self.s2, self.sin * self.s1)
+    }
+
+    /// Return the power of the bin, i.e. the squared magnitude of its value.
+    ///
+    /// This is cheaper to compute than the value itself.
+    pub fn power(&self) -> f32 {
+        self.s1 * self.s1 + self.s2 * self.s2 - 2. * self.cos * self.s1 * self.s2
+    }
+
+    /// Reset the detector, discarding all processed samples.
+    pub fn reset(&mut self) {
+        self.s1 = 0.;
+        self.s2 = 0.;
+    }
+}
+
+/// Compute the value of bin `k` of the DFT of `input`.
+///
+/// The DFT size is determined by the length of the input slice.
+///
+/// # Example
+///
+/// ```
+/// use microfft::goertzel;
+///
+/// let input = [1., 2., 3., 4.];
+/// let value = goertzel::value(&input, 1);
+/// assert!((value.re - -2.).abs() < 1e-6);
+/// assert!((value.im - 2.).abs() < 1e-6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` is empty.
+pub fn value(input: &[f32], k: usize) -> Complex32 {
+    let mut goertzel = Goertzel::new(k, input.len());
+    goertzel.extend(input);
+    goertzel.value()
+}
+
+/// Compute the power of bin `k` of the DFT of `input`.
+///
+/// The DFT size is determined by the length of the input slice.
+///
+/// # Example
+///
+/// ```
+/// use microfft::goertzel;
+///
+/// let input = [1., 2., 3., 4.];
+/// let power = goertzel::power(&input, 1);
+/// assert!((power - 8.).abs() < 1e-5);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` is empty.
+pub fn power(input: &[f32], k: usize) -> f32 {
+    let mut goertzel = Goertzel::new(k, input.len());
+    goertzel.extend(input);
+    goertzel.power()
+}
@@ -0,0 +1,941 @@
+//! Discrete Hartley transform (DHT)
+//!
+//! The DHT is a real-valued alternative to the DFT of real values:
+//!
+//! ```text
+//! H[k] = sum(x[n] * cas(2π * n * k / N) for n in 0..N)
+//! ```
+//!
+//! with `cas(t) = cos(t) + sin(t)`. It is related to the RFFT output `F` by
+//! `H[k] = Re(F[k]) - Im(F[k])` and computed in-place from the output of the
+//! packed RFFT, with an additional pass that brings the values into natural
+//! order.
+//!
+//! The DHT is its own inverse, up to a factor of `N`. The inverse DHT
+//! (`idht_*`) scales its output by `1/N`, so it exactly undoes the
+//! corresponding DHT.
+
+use crate::{permute::permute, rfft::*};
+use num_complex::Complex32;
+
+#[inline]
+fn dht<R: RFft>(x: &mut [f32]) -> &mut [f32] {
+    let n = R::N;
+    debug_assert_eq!(x.len(), n);
+
+    // DC and Nyquist are real and stay in place
+    let y = R::transform_packed(x);
+
+    // `H[k]` and `H[N - k]` are the difference and sum of the real and
+    // imaginary part of `F[k]`
+    for y_k in y.iter_mut().skip(1) {
+        *y_k = Complex32::new(y_k.re - y_k.im, y_k.re + y_k.im);
+    }
+
+    let x = as_real(y);
+    permute(x, |i| match i {
+        0 => 0,
+        1 => n / 2,
+        i if i % 2 == 0 => i / 2,
+        i => n - i / 2,
+    });
+    x
+}
+
+#[inline]
+fn idht<R: RFft>(x: &mut [f32]) -> &mut [f32] {
+    let x = dht::<R>(x);
+    let scale = 1. / R::N as f32;
+    for value in x.iter_mut() {
+        *value *= scale;
+    }
+    x
+}
+
+/// Perform an in-place 2-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_2;
+///
+/// let mut input = [0.; 2];
+/// let result = dht_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn dht_2(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2);
+    dht::<RFftN2>(input)
+}
+
+/// Perform an in-place 4-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_4;
+///
+/// let mut input = [0.; 4];
+/// let result = dht_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn dht_4(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4);
+    dht::<RFftN4>(input)
+}
+
+/// Perform an in-place 8-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_8;
+///
+/// let mut input = [0.; 8];
+/// let result = dht_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_8(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8);
+    dht::<RFftN8>(input)
+}
+
+/// Perform an in-place 16-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_16;
+///
+/// let mut input = [0.; 16];
+/// let result = dht_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_16(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16);
+    dht::<RFftN16>(input)
+}
+
+/// Perform an in-place 32-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_32;
+///
+/// let mut input = [0.; 32];
+/// let result = dht_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_32(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32);
+    dht::<RFftN32>(input)
+}
+
+/// Perform an in-place 64-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_64;
+///
+/// let mut input = [0.; 64];
+/// let result = dht_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_64(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 64);
+    dht::<RFftN64>(input)
+}
+
+/// Perform an in-place 128-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_128;
+///
+/// let mut input = [0.; 128];
+/// let result = dht_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_128(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 128);
+    dht::<RFftN128>(input)
+}
+
+/// Perform an in-place 256-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_256;
+///
+/// let mut input = [0.; 256];
+/// let result = dht_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_256(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 256);
+    dht::<RFftN256>(input)
+}
+
+/// Perform an in-place 512-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_512;
+///
+/// let mut input = [0.; 512];
+/// let result = dht_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_512(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 512);
+    dht::<RFftN512>(input)
+}
+
+/// Perform an in-place 1024-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_1024;
+///
+/// let mut input = [0.; 1024];
+/// let result = dht_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_1024(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 1024);
+    dht::<RFftN1024>(input)
+}
+
+/// Perform an in-place 2048-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_2048;
+///
+/// let mut input = [0.; 2048];
+/// let result = dht_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_2048(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2048);
+    dht::<RFftN2048>(input)
+}
+
+/// Perform an in-place 4096-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_4096;
+///
+/// let mut input = [0.; 4096];
+/// let result = dht_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_4096(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4096);
+    dht::<RFftN4096>(input)
+}
+
+/// Perform an in-place 8192-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = dht_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn dht_8192(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8192);
+    dht::<RFftN8192>(input)
+}
+
+/// Perform an in-place 16384-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = dht_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dht_16384(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16384);
+    dht::<RFftN16384>(input)
+}
+
+/// Perform an in-place 32768-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = dht_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn dht_32768(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32768);
+    dht::<RFftN32768>(input)
+}
+
+/// Perform an in-place 65536-point DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::dht_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = dht_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn dht_65536(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 65536);
+    dht::<RFftN65536>(input)
+}
+
+/// Perform an in-place 2-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_2;
+///
+/// let mut input = [0.; 2];
+/// let result = idht_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn idht_2(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2);
+    idht::<RFftN2>(input)
+}
+
+/// Perform an in-place 4-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_4;
+///
+/// let mut input = [0.; 4];
+/// let result = idht_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn idht_4(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4);
+    idht::<RFftN4>(input)
+}
+
+/// Perform an in-place 8-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_8;
+///
+/// let mut input = [0.; 8];
+/// let result = idht_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_8(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8);
+    idht::<RFftN8>(input)
+}
+
+/// Perform an in-place 16-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_16;
+///
+/// let mut input = [0.; 16];
+/// let result = idht_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_16(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16);
+    idht::<RFftN16>(input)
+}
+
+/// Perform an in-place 32-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_32;
+///
+/// let mut input = [0.; 32];
+/// let result = idht_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_32(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32);
+    idht::<RFftN32>(input)
+}
+
+/// Perform an in-place 64-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_64;
+///
+/// let mut input = [0.; 64];
+/// let result = idht_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_64(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 64);
+    idht::<RFftN64>(input)
+}
+
+/// Perform an in-place 128-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_128;
+///
+/// let mut input = [0.; 128];
+/// let result = idht_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_128(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 128);
+    idht::<RFftN128>(input)
+}
+
+/// Perform an in-place 256-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_256;
+///
+/// let mut input = [0.; 256];
+/// let result = idht_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_256(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 256);
+    idht::<RFftN256>(input)
+}
+
+/// Perform an in-place 512-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_512;
+///
+/// let mut input = [0.; 512];
+/// let result = idht_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_512(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 512);
+    idht::<RFftN512>(input)
+}
+
+/// Perform an in-place 1024-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_1024;
+///
+/// let mut input = [0.; 1024];
+/// let result = idht_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_1024(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 1024);
+    idht::<RFftN1024>(input)
+}
+
+/// Perform an in-place 2048-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_2048;
+///
+/// let mut input = [0.; 2048];
+/// let result = idht_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_2048(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2048);
+    idht::<RFftN2048>(input)
+}
+
+/// Perform an in-place 4096-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_4096;
+///
+/// let mut input = [0.; 4096];
+/// let result = idht_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_4096(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4096);
+    idht::<RFftN4096>(input)
+}
+
+/// Perform an in-place 8192-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = idht_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn idht_8192(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8192);
+    idht::<RFftN8192>(input)
+}
+
+/// Perform an in-place 16384-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = idht_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn idht_16384(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16384);
+    idht::<RFftN16384>(input)
+}
+
+/// Perform an in-place 32768-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = idht_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn idht_32768(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32768);
+    idht::<RFftN32768>(input)
+}
+
+/// Perform an in-place 65536-point inverse DHT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::hartley::idht_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = idht_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn idht_65536(input: &mut [f32]) -> &mut [f32] {
+    assert_eq!(input.len(), 65536);
+    idht::<RFftN65536>(input)
+}
@@ -10,6 +10,33 @@
 //! provided ([`real`]). An `N`-point RFFT internally computes an `N/2`-point
 //! CFFT, making it roughly twice as fast a the complex variant.
 //!
+//! Inverse transforms on complex values are provided as well, with a choice
+//! of [`Scaling`] for their output.
+//!
+//! If the `f64` feature is enabled, the same transforms are also provided for
+//! double-precision values ([`complex64`], [`real64`]). The `q15` and `q31`
+//! features enable forward transforms on Q15 ([`complex_q15`], [`real_q15`])
+//! and Q31 ([`complex_q31`], [`real_q31`]) fixed-point values, for targets
+//! without a floating-point unit.
+//!
+//! The [`zoom`] module provides a zoom FFT, for inspecting a narrow frequency
+//! band at high resolution. Single bins can be evaluated using the Goertzel
+//! algorithm, provided by the [`goertzel`] module. The [`sliding`] module
+//! keeps a spectrum up to date while samples arrive one at a time.
+//!
+//! The [`dct`] module provides discrete cosine transforms, computed using
+//! the RFFT or the CFFT. The modified discrete cosine transform used by audio
+//! codecs is provided by the [`mdct`] module, discrete sine transforms by the
+//! [`dst`] module. The [`hartley`] module provides the discrete Hartley
+//! transform, a real-valued alternative to the RFFT.
+//!
+//! Window functions to apply before computing a spectrum are provided by the
+//! [`window`] module. The [`spectrum`] module converts the RFFT output into
+//! magnitude, power or decibel spectra, and the [`psd`] module estimates
+//! power spectral densities using Welch's method. Spectrograms of continuous
+//! streams of samples are computed by the short-time Fourier transform in the
+//! [`stft`] module.
+//!
 //! # Example
 //!
 //! ```
@@ -32,22 +59,198 @@
 //! ```
 //!
 //! [`complex`]: complex/index.html
+//! [`complex64`]: complex64/index.html
+//! [`complex_q15`]: complex_q15/index.html
+//! [`complex_q31`]: complex_q31/index.html
+//! [`dct`]: dct/index.html
+//! [`dst`]: dst/index.html
+//! [`goertzel`]: goertzel/index.html
+//! [`hartley`]: hartley/index.html
+//! [`mdct`]: mdct/index.html
+//! [`psd`]: psd/index.html
+//! [`real64`]: real64/index.html
+//! [`real_q15`]: real_q15/index.html
+//! [`real_q31`]: real_q31/index.html
 //! [`real`]: real/index.html
+//! [`sliding`]: sliding/index.html
+//! [`spectrum`]: spectrum/index.html
+//! [`stft`]: stft/index.html
+//! [`window`]: window/index.html
+//! [`zoom`]: zoom/index.html
 //! [`Complex32`]: type.Complex32.html
+//! [`Scaling`]: enum.Scaling.html
 
 #![no_std]
 #![deny(missing_docs)]
 #![warn(rust_2018_idioms)]
 
 pub mod complex;
+#[cfg(feature = "f64")]
+pub mod complex64;
+#[cfg(feature = "q15")]
+pub mod complex_q15;
+#[cfg(feature = "q31")]
+pub mod complex_q31;
+pub mod dct;
+pub mod dst;
+pub mod goertzel;
+pub mod hartley;
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub mod mdct;
+pub mod psd;
 pub mod real;
+#[cfg(feature = "f64")]
+pub mod real64;
+#[cfg(feature = "q15")]
+pub mod real_q15;
+#[cfg(feature = "q31")]
+pub mod real_q31;
+pub mod sliding;
+pub mod spectrum;
+pub mod stft;
+pub mod window;
+pub mod zoom;
 
 pub use num_complex::Complex32;
+#[cfg(feature = "f64")]
+pub use num_complex::Complex64;
+
+/// A complex number in Q15 fixed-point format.
+#[cfg(feature = "q15")]
+pub type ComplexQ15 = num_complex::Complex<i16>;
+
+/// A complex number in Q31 fixed-point format.
+#[cfg(feature = "q31")]
+pub type ComplexQ31 = num_complex::Complex<i32>;
+
+use core::fmt;
 
+mod bluestein;
 mod cfft;
+#[cfg(any(feature = "q15", feature = "q31"))]
+mod fixed;
+mod float;
+mod math;
+mod mixed;
+mod permute;
 mod rfft;
 mod tables;
 
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// FFT of a fixed size.
+///
+/// This trait is implemented by the size marker types of the CFFT (e.g.
+/// [`complex::CFftN16`]) and the RFFT (e.g. [`real::RFftN16`]). It allows
+/// writing code that is generic over the FFT size and kind. The trait is
+/// sealed and cannot be implemented outside of microfft.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::RFftN16, Complex32, Fft};
+///
+/// fn spectrum<F: Fft>(samples: &mut F::Input) -> &mut F::Output {
+///     F::transform(samples)
+/// }
+///
+/// let mut samples = [0.; 16];
+/// let result: &mut [Complex32; 8] = spectrum::<RFftN16>(&mut samples);
+/// ```
+///
+/// [`complex::CFftN16`]: complex/struct.CFftN16.html
+/// [`real::RFftN16`]: real/struct.RFftN16.html
+pub trait Fft: sealed::Sealed {
+    /// The input buffer type.
+    type Input;
+    /// The output buffer type.
+    type Output;
+
+    /// The FFT size.
+    const N: usize;
+    /// The base-2 logarithm of the FFT size.
+    const LOG2_N: usize;
+
+    /// Perform an in-place FFT.
+    fn transform(input: &mut Self::Input) -> &mut Self::Output;
+}
+
+/// Scaling applied to the output of inverse transforms.
+///
+/// An unscaled inverse transform of a forward transform's output yields the
+/// original input multiplied by the transform size `N`. Normalizing the
+/// output scales it by `1/N` instead, exactly restoring the original input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scaling {
+    /// Leave the output unscaled.
+    Unscaled,
+    /// Scale the output by `1/N`.
+    Normalized,
+}
+
+/// Error returned by transforms on inputs whose size is only known at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FftError {
+    /// The input size is not a power of two.
+    NotPowerOfTwo,
+    /// The input size is smaller than the smallest supported FFT size.
+    TooSmall,
+    /// The input size exceeds the maximum FFT size selected by the `maxn-*`
+    /// features. For mixed-radix transforms, this refers to the power-of-two
+    /// factor of the input size.
+    TooLarge,
+    /// The input size has prime factors other than 2, 3 and 5, which are not
+    /// supported by mixed-radix transforms.
+    UnsupportedFactor,
+    /// The scratch buffer provided to a transform is too small.
+    ScratchTooSmall,
+}
+
+impl FftError {
+    /// Return the error describing why `n` is not a supported FFT size.
+    pub(crate) fn for_size(n: usize) -> Self {
+        // empty inputs and the (trivial) 1-point FFT are not supported
+        if n < 2 {
+            Self::TooSmall
+        } else if !n.is_power_of_two() {
+            Self::NotPowerOfTwo
+        } else {
+            Self::TooLarge
+        }
+    }
+}
+
+impl fmt::Display for FftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::NotPowerOfTwo => "FFT size is not a power of two",
+            Self::TooSmall => "FFT size is smaller than the minimum size",
+            Self::TooLarge => "FFT size exceeds the maximum size enabled by `maxn-*`",
+            Self::UnsupportedFactor => "FFT size has prime factors other than 2, 3 and 5",
+            Self::ScratchTooSmall => "scratch buffer is too small for the FFT size",
+        };
+        f.write_str(msg)
+    }
+}
+
 use static_assertions::assert_cfg;
 
 assert_cfg!(
@@ -63,6 +266,10 @@ assert_cfg!(
         feature = "maxn-1024",
         feature = "maxn-2048",
         feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
     ),
     "One of the `maxn-*` features of this crate must be set."
 );
@@ -0,0 +1,137 @@
+//! Elementary functions for computing twiddle factors that cannot be looked
+//! up in the sine tables, and for converting spectra to magnitudes and
+//! decibels without depending on libm.
+
+use core::f32::consts::{FRAC_1_SQRT_2, FRAC_PI_4, LN_2, LOG10_E};
+
+/// Compute `(cos(2π * k / n), sin(2π * k / n))`.
+///
+/// The angle is reduced to the range `[0, π/4]` using exact integer
+/// arithmetic, which keeps the result accurate for large `k` and `n`.
+pub(crate) fn cos_sin(k: usize, n: usize) -> (f32, f32) {
+    debug_assert!(n > 0);
+
+    // split the angle into octant and remainder: `8k = octant * n + rem`
+    let k = (k % n) as u64;
+    let n = n as u64;
+    let octant = k * 8 / n;
+    let rem = k * 8 % n;
+
+    let (rem, rem_rev) = (rem as f32 / n as f32, (n - rem) as f32 / n as f32);
+    octant_cos_sin(octant, rem, rem_rev)
+}
+
+/// Compute `(cos(2π * t), sin(2π * t))`.
+///
+/// The angle is reduced to the range `[0, π/4]` in double precision, which
+/// keeps the result accurate for large `t`.
+pub(crate) fn cos_sin_turns(t: f64) -> (f32, f32) {
+    let t8 = (t - floor(t)) * 8.;
+    let octant = t8 as u64;
+    let rem = t8 - octant as f64;
+    octant_cos_sin(octant, rem as f32, (1. - rem) as f32)
+}
+
+/// Compute cosine and sine of the angle `(octant + rem) * π/4`, given
+/// `rem_rev = 1 - rem`.
+fn octant_cos_sin(octant: u64, rem: f32, rem_rev: f32) -> (f32, f32) {
+    // angle within the quadrant, in `[0, π/2)`
+    let (cos, sin) = if octant & 1 == 0 {
+        let x = rem * FRAC_PI_4;
+        (cos_poly(x), sin_poly(x))
+    } else {
+        let x = rem_rev * FRAC_PI_4;
+        (sin_poly(x), cos_poly(x))
+    };
+
+    match octant % 8 / 2 {
+        0 => (cos, sin),
+        1 => (-sin, cos),
+        2 => (-cos, -sin),
+        _ => (sin, -cos),
+    }
+}
+
+/// Round `x` towards negative infinity.
+fn floor(x: f64) -> f64 {
+    let t = x as i64 as f64;
+    if t > x {
+        t - 1.
+    } else {
+        t
+    }
+}
+
+/// Taylor approximation of `sin(x)`, accurate for `x` in `[0, π/4]`.
+fn sin_poly(x: f32) -> f32 {
+    let x2 = x * x;
+    x * (1. - x2 / 6. * (1. - x2 / 20. * (1. - x2 / 42. * (1. - x2 / 72.))))
+}
+
+/// Taylor approximation of `cos(x)`, accurate for `x` in `[0, π/4]`.
+fn cos_poly(x: f32) -> f32 {
+    let x2 = x * x;
+    1. - x2 / 2. * (1. - x2 / 12. * (1. - x2 / 30. * (1. - x2 / 56. * (1. - x2 / 90.))))
+}
+
+/// Compute `sqrt(x)` for non-negative `x`.
+///
+/// An initial estimate is derived from the bit representation of `x` and
+/// refined by two Newton iterations, giving a relative error below `3e-7`.
+pub(crate) fn sqrt(x: f32) -> f32 {
+    if x < 0. {
+        return f32::NAN;
+    } else if x == 0. || x.is_infinite() || x.is_nan() {
+        return x;
+    }
+
+    let (x, scale) = if x < f32::MIN_POSITIVE {
+        // normalize subnormal values, `2^24` has an exact square root
+        (x * 16_777_216., 1. / 4096.)
+    } else {
+        (x, 1.)
+    };
+
+    // halving the biased exponent approximates the square root within 4%
+    let mut y = f32::from_bits(0x1fbd_1df5 + (x.to_bits() >> 1));
+    y = (y + x / y) / 2.;
+    y = (y + x / y) / 2.;
+    y * scale
+}
+
+/// Compute `log10(x)` for non-negative `x`.
+///
+/// The value is split into a power of two and a mantissa in `[√½, √2)`,
+/// whose logarithm is approximated by a series with an absolute error below
+/// `1e-7`.
+pub(crate) fn log10(x: f32) -> f32 {
+    if x < 0. {
+        return f32::NAN;
+    } else if x == 0. {
+        return f32::NEG_INFINITY;
+    } else if x.is_infinite() || x.is_nan() {
+        return x;
+    }
+
+    let (x, offset) = if x < f32::MIN_POSITIVE {
+        (x * 16_777_216., -24)
+    } else {
+        (x, 0)
+    };
+
+    // `x = m * 2^e` with `m` in `[1, 2)`
+    let bits = x.to_bits();
+    let mut e = ((bits >> 23) & 0xff) as i32 - 127 + offset;
+    let mut m = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
+    if m >= 2. * FRAC_1_SQRT_2 {
+        m /= 2.;
+        e += 1;
+    }
+
+    // `ln(m) = 2 * atanh(s)` with `s = (m - 1) / (m + 1)` in `[-0.172, 0.172]`
+    let s = (m - 1.) / (m + 1.);
+    let s2 = s * s;
+    let ln_m = 2. * s * (1. + s2 * (1. / 3. + s2 * (1. / 5. + s2 * (1. / 7.))));
+
+    (e as f32 * LN_2 + ln_m) * LOG10_E
+}
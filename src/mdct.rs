@@ -0,0 +1,1168 @@
+//! Modified discrete cosine transform (MDCT)
+//!
+//! The MDCT (`mdct_*`) transforms a block of `N` samples into `N/2`
+//! coefficients. Consecutive blocks overlap by `N/2` samples, so each sample
+//! contributes to two blocks. The inverse MDCT (`imdct_*`) reconstructs
+//! `N` samples from `N/2` coefficients, containing time-domain aliasing that
+//! is cancelled by adding the second half of the previous block's output to
+//! the first half of the current one (TDAC).
+//!
+//! Both transforms apply a window of `N` values, which is provided by the
+//! caller. To achieve perfect reconstruction, the window has to be symmetric
+//! and satisfy the Princen-Bradley condition `w[n]² + w[n + N/2]² = 1`, like
+//! the sine window `w[n] = sin(π * (n + 1/2) / N)`.
+//!
+//! The overlap-add is performed by `imdct_*` using a caller-supplied buffer
+//! of `N/2` values, which holds the second half of the previous block's
+//! output. It should be initialized with zeros and passed to each call for
+//! a stream of blocks.
+//!
+//! The MDCT is defined without normalization, while the inverse MDCT is
+//! scaled by `4/N`:
+//!
+//! ```text
+//! X[k] = sum(w[n] * x[n] * cos(π/M * (n + 1/2 + M/2) * (k + 1/2)) for n in 0..N)
+//! y[n] = w[n] * 2/M * sum(X[k] * cos(π/M * (n + 1/2 + M/2) * (k + 1/2)) for k in 0..M)
+//! ```
+//!
+//! with `M = N/2`. Both transforms are computed using an `M`-point DCT-IV,
+//! which in turn uses an `N/4`-point CFFT. The smallest supported size is
+//! `N = 8`.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::mdct::{imdct_16, mdct_16};
+//! use std::f32::consts::PI;
+//!
+//! let signal: Vec<f32> = (0..32).map(|i| (i as f32 * 0.3).sin()).collect();
+//! let window: Vec<_> = (0..16)
+//!     .map(|n| (PI * (n as f32 + 0.5) / 16.).sin())
+//!     .collect();
+//!
+//! let mut overlap = [0.; 8];
+//! let mut coeffs = [0.; 8];
+//! let mut output = Vec::new();
+//! for block in signal.windows(16).step_by(8) {
+//!     mdct_16(block, &window, &mut coeffs);
+//!     output.extend_from_slice(imdct_16(&mut coeffs, &window, &mut overlap));
+//! }
+//!
+//! // the output is delayed by one block, the first block is incomplete
+//! for (y, x) in output[8..].iter().zip(&signal[8..]) {
+//!     assert!((y - x).abs() < 1e-5);
+//! }
+//! ```
+
+use crate::{cfft::*, dct::dct4};
+
+#[inline]
+fn mdct<'a, C: CFft>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    let m = C::N * 2;
+    let h = m / 2;
+    debug_assert_eq!(input.len(), 2 * m);
+    debug_assert_eq!(window.len(), 2 * m);
+    debug_assert_eq!(output.len(), m);
+
+    let x = |n: usize| input[n] * window[n];
+
+    // fold the windowed quarters `(a, b, c, d)` of the input into
+    // `(-c_r - d, a - b_r)`
+    for n in 0..h {
+        output[n] = -x(m + h - 1 - n) - x(m + h + n);
+        output[h + n] = x(n) - x(m - 1 - n);
+    }
+
+    dct4::<C>(output)
+}
+
+#[inline]
+fn imdct<'a, C: CFft>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    let m = C::N * 2;
+    let h = m / 2;
+    debug_assert_eq!(input.len(), m);
+    debug_assert_eq!(window.len(), 2 * m);
+    debug_assert_eq!(overlap.len(), m);
+
+    let u = dct4::<C>(input);
+    let scale = 2. / m as f32;
+
+    // The unfolded output is `(u_2, -u_2r, -u_1r, -u_1)`, where `u_1` and
+    // `u_2` are the halves of `u`. The values at `n`, `h - 1 - n`, `h + n`
+    // and `m - 1 - n` only depend on each other, so they are updated
+    // together.
+    for n in 0..h / 2 {
+        let (p, q) = (u[h + n] * scale, u[h - 1 - n] * scale);
+        let (r, s) = (u[m - 1 - n] * scale, u[n] * scale);
+
+        for &(i, p, q) in &[(n, p, q), (h - 1 - n, r, s)] {
+            let j = m - 1 - i;
+            u[i] = overlap[i] + p * window[i];
+            u[j] = overlap[j] - p * window[j];
+            overlap[i] = -q * window[m + i];
+            overlap[j] = -q * window[m + j];
+        }
+    }
+
+    u
+}
+
+/// Perform an 8-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `4` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_8;
+///
+/// let input = [0.; 8];
+/// let window = [1.; 8];
+/// let mut output = [0.; 4];
+/// let result = mdct_8(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `8`, or if
+/// `output` has a length other than `4`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_8<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 8);
+    assert_eq!(window.len(), 8);
+    assert_eq!(output.len(), 4);
+    mdct::<CFftN2>(input, window, output)
+}
+
+/// Perform a 16-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `8` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_16;
+///
+/// let input = [0.; 16];
+/// let window = [1.; 16];
+/// let mut output = [0.; 8];
+/// let result = mdct_16(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `16`, or if
+/// `output` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_16<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 16);
+    assert_eq!(window.len(), 16);
+    assert_eq!(output.len(), 8);
+    mdct::<CFftN4>(input, window, output)
+}
+
+/// Perform a 32-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `16` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_32;
+///
+/// let input = [0.; 32];
+/// let window = [1.; 32];
+/// let mut output = [0.; 16];
+/// let result = mdct_32(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `32`, or if
+/// `output` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_32<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 32);
+    assert_eq!(window.len(), 32);
+    assert_eq!(output.len(), 16);
+    mdct::<CFftN8>(input, window, output)
+}
+
+/// Perform a 64-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `32` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_64;
+///
+/// let input = [0.; 64];
+/// let window = [1.; 64];
+/// let mut output = [0.; 32];
+/// let result = mdct_64(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `64`, or if
+/// `output` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_64<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 64);
+    assert_eq!(window.len(), 64);
+    assert_eq!(output.len(), 32);
+    mdct::<CFftN16>(input, window, output)
+}
+
+/// Perform a 128-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `64` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_128;
+///
+/// let input = [0.; 128];
+/// let window = [1.; 128];
+/// let mut output = [0.; 64];
+/// let result = mdct_128(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `128`, or if
+/// `output` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_128<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 128);
+    assert_eq!(window.len(), 128);
+    assert_eq!(output.len(), 64);
+    mdct::<CFftN32>(input, window, output)
+}
+
+/// Perform a 256-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `128` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_256;
+///
+/// let input = [0.; 256];
+/// let window = [1.; 256];
+/// let mut output = [0.; 128];
+/// let result = mdct_256(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `256`, or if
+/// `output` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_256<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 256);
+    assert_eq!(window.len(), 256);
+    assert_eq!(output.len(), 128);
+    mdct::<CFftN64>(input, window, output)
+}
+
+/// Perform a 512-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `256` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_512;
+///
+/// let input = [0.; 512];
+/// let window = [1.; 512];
+/// let mut output = [0.; 256];
+/// let result = mdct_512(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `512`, or if
+/// `output` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_512<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 512);
+    assert_eq!(window.len(), 512);
+    assert_eq!(output.len(), 256);
+    mdct::<CFftN128>(input, window, output)
+}
+
+/// Perform a 1024-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `512` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_1024;
+///
+/// let input = [0.; 1024];
+/// let window = [1.; 1024];
+/// let mut output = [0.; 512];
+/// let result = mdct_1024(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `1024`, or if
+/// `output` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_1024<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 1024);
+    assert_eq!(window.len(), 1024);
+    assert_eq!(output.len(), 512);
+    mdct::<CFftN256>(input, window, output)
+}
+
+/// Perform a 2048-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `1024` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_2048;
+///
+/// let input = [0.; 2048];
+/// let window = [1.; 2048];
+/// let mut output = [0.; 1024];
+/// let result = mdct_2048(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `2048`, or if
+/// `output` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_2048<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 2048);
+    assert_eq!(window.len(), 2048);
+    assert_eq!(output.len(), 1024);
+    mdct::<CFftN512>(input, window, output)
+}
+
+/// Perform a 4096-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `2048` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_4096;
+///
+/// let input = [0.; 4096];
+/// let window = [1.; 4096];
+/// let mut output = [0.; 2048];
+/// let result = mdct_4096(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `4096`, or if
+/// `output` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_4096<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 4096);
+    assert_eq!(window.len(), 4096);
+    assert_eq!(output.len(), 2048);
+    mdct::<CFftN1024>(input, window, output)
+}
+
+/// Perform an 8192-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `4096` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_8192;
+///
+/// let input = [0.; 8192];
+/// let window = [1.; 8192];
+/// let mut output = [0.; 4096];
+/// let result = mdct_8192(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `8192`, or if
+/// `output` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn mdct_8192<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 8192);
+    assert_eq!(window.len(), 8192);
+    assert_eq!(output.len(), 4096);
+    mdct::<CFftN2048>(input, window, output)
+}
+
+/// Perform a 16384-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `8192` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_16384;
+///
+/// let input = [0.; 16384];
+/// let window = [1.; 16384];
+/// let mut output = [0.; 8192];
+/// let result = mdct_16384(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `16384`, or if
+/// `output` has a length other than `8192`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn mdct_16384<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 16384);
+    assert_eq!(window.len(), 16384);
+    assert_eq!(output.len(), 8192);
+    mdct::<CFftN4096>(input, window, output)
+}
+
+/// Perform a 32768-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `16384` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_32768;
+///
+/// let input = [0.; 32768];
+/// let window = [1.; 32768];
+/// let mut output = [0.; 16384];
+/// let result = mdct_32768(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `32768`, or if
+/// `output` has a length other than `16384`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn mdct_32768<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 32768);
+    assert_eq!(window.len(), 32768);
+    assert_eq!(output.len(), 16384);
+    mdct::<CFftN8192>(input, window, output)
+}
+
+/// Perform a 65536-point MDCT.
+///
+/// The input block is multiplied by `window` and transformed into the
+/// `32768` coefficients written to `output`.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::mdct_65536;
+///
+/// let input = [0.; 65536];
+/// let window = [1.; 65536];
+/// let mut output = [0.; 32768];
+/// let result = mdct_65536(&input, &window, &mut output);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `window` has a length other than `65536`, or if
+/// `output` has a length other than `32768`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn mdct_65536<'a>(input: &[f32], window: &[f32], output: &'a mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 65536);
+    assert_eq!(window.len(), 65536);
+    assert_eq!(output.len(), 32768);
+    mdct::<CFftN16384>(input, window, output)
+}
+
+/// Perform an in-place 8-point inverse MDCT.
+///
+/// The `4` coefficients in `input` are transformed into `8` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_8;
+///
+/// let mut input = [0.; 4];
+/// let window = [1.; 8];
+/// let mut overlap = [0.; 4];
+/// let result = imdct_8(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `4`, or if
+/// `window` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_8<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 4);
+    assert_eq!(window.len(), 8);
+    assert_eq!(overlap.len(), 4);
+    imdct::<CFftN2>(input, window, overlap)
+}
+
+/// Perform an in-place 16-point inverse MDCT.
+///
+/// The `8` coefficients in `input` are transformed into `16` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_16;
+///
+/// let mut input = [0.; 8];
+/// let window = [1.; 16];
+/// let mut overlap = [0.; 8];
+/// let result = imdct_16(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `8`, or if
+/// `window` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_16<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 8);
+    assert_eq!(window.len(), 16);
+    assert_eq!(overlap.len(), 8);
+    imdct::<CFftN4>(input, window, overlap)
+}
+
+/// Perform an in-place 32-point inverse MDCT.
+///
+/// The `16` coefficients in `input` are transformed into `32` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_32;
+///
+/// let mut input = [0.; 16];
+/// let window = [1.; 32];
+/// let mut overlap = [0.; 16];
+/// let result = imdct_32(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `16`, or if
+/// `window` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_32<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 16);
+    assert_eq!(window.len(), 32);
+    assert_eq!(overlap.len(), 16);
+    imdct::<CFftN8>(input, window, overlap)
+}
+
+/// Perform an in-place 64-point inverse MDCT.
+///
+/// The `32` coefficients in `input` are transformed into `64` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_64;
+///
+/// let mut input = [0.; 32];
+/// let window = [1.; 64];
+/// let mut overlap = [0.; 32];
+/// let result = imdct_64(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `32`, or if
+/// `window` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_64<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 32);
+    assert_eq!(window.len(), 64);
+    assert_eq!(overlap.len(), 32);
+    imdct::<CFftN16>(input, window, overlap)
+}
+
+/// Perform an in-place 128-point inverse MDCT.
+///
+/// The `64` coefficients in `input` are transformed into `128` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_128;
+///
+/// let mut input = [0.; 64];
+/// let window = [1.; 128];
+/// let mut overlap = [0.; 64];
+/// let result = imdct_128(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `64`, or if
+/// `window` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_128<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 64);
+    assert_eq!(window.len(), 128);
+    assert_eq!(overlap.len(), 64);
+    imdct::<CFftN32>(input, window, overlap)
+}
+
+/// Perform an in-place 256-point inverse MDCT.
+///
+/// The `128` coefficients in `input` are transformed into `256` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_256;
+///
+/// let mut input = [0.; 128];
+/// let window = [1.; 256];
+/// let mut overlap = [0.; 128];
+/// let result = imdct_256(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `128`, or if
+/// `window` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_256<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 128);
+    assert_eq!(window.len(), 256);
+    assert_eq!(overlap.len(), 128);
+    imdct::<CFftN64>(input, window, overlap)
+}
+
+/// Perform an in-place 512-point inverse MDCT.
+///
+/// The `256` coefficients in `input` are transformed into `512` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_512;
+///
+/// let mut input = [0.; 256];
+/// let window = [1.; 512];
+/// let mut overlap = [0.; 256];
+/// let result = imdct_512(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `256`, or if
+/// `window` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_512<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 256);
+    assert_eq!(window.len(), 512);
+    assert_eq!(overlap.len(), 256);
+    imdct::<CFftN128>(input, window, overlap)
+}
+
+/// Perform an in-place 1024-point inverse MDCT.
+///
+/// The `512` coefficients in `input` are transformed into `1024` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_1024;
+///
+/// let mut input = [0.; 512];
+/// let window = [1.; 1024];
+/// let mut overlap = [0.; 512];
+/// let result = imdct_1024(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `512`, or if
+/// `window` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_1024<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 512);
+    assert_eq!(window.len(), 1024);
+    assert_eq!(overlap.len(), 512);
+    imdct::<CFftN256>(input, window, overlap)
+}
+
+/// Perform an in-place 2048-point inverse MDCT.
+///
+/// The `1024` coefficients in `input` are transformed into `2048` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_2048;
+///
+/// let mut input = [0.; 1024];
+/// let window = [1.; 2048];
+/// let mut overlap = [0.; 1024];
+/// let result = imdct_2048(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `1024`, or if
+/// `window` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_2048<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 1024);
+    assert_eq!(window.len(), 2048);
+    assert_eq!(overlap.len(), 1024);
+    imdct::<CFftN512>(input, window, overlap)
+}
+
+/// Perform an in-place 4096-point inverse MDCT.
+///
+/// The `2048` coefficients in `input` are transformed into `4096` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_4096;
+///
+/// let mut input = [0.; 2048];
+/// let window = [1.; 4096];
+/// let mut overlap = [0.; 2048];
+/// let result = imdct_4096(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `2048`, or if
+/// `window` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_4096<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 2048);
+    assert_eq!(window.len(), 4096);
+    assert_eq!(overlap.len(), 2048);
+    imdct::<CFftN1024>(input, window, overlap)
+}
+
+/// Perform an in-place 8192-point inverse MDCT.
+///
+/// The `4096` coefficients in `input` are transformed into `8192` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_8192;
+///
+/// let mut input = [0.; 4096];
+/// let window = [1.; 8192];
+/// let mut overlap = [0.; 4096];
+/// let result = imdct_8192(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `4096`, or if
+/// `window` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn imdct_8192<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 4096);
+    assert_eq!(window.len(), 8192);
+    assert_eq!(overlap.len(), 4096);
+    imdct::<CFftN2048>(input, window, overlap)
+}
+
+/// Perform an in-place 16384-point inverse MDCT.
+///
+/// The `8192` coefficients in `input` are transformed into `16384` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_16384;
+///
+/// let mut input = [0.; 8192];
+/// let window = [1.; 16384];
+/// let mut overlap = [0.; 8192];
+/// let result = imdct_16384(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `8192`, or if
+/// `window` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn imdct_16384<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 8192);
+    assert_eq!(window.len(), 16384);
+    assert_eq!(overlap.len(), 8192);
+    imdct::<CFftN4096>(input, window, overlap)
+}
+
+/// Perform an in-place 32768-point inverse MDCT.
+///
+/// The `16384` coefficients in `input` are transformed into `32768` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_32768;
+///
+/// let mut input = [0.; 16384];
+/// let window = [1.; 32768];
+/// let mut overlap = [0.; 16384];
+/// let result = imdct_32768(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `16384`, or if
+/// `window` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn imdct_32768<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 16384);
+    assert_eq!(window.len(), 32768);
+    assert_eq!(overlap.len(), 16384);
+    imdct::<CFftN8192>(input, window, overlap)
+}
+
+/// Perform an in-place 65536-point inverse MDCT.
+///
+/// The `32768` coefficients in `input` are transformed into `65536` samples,
+/// which are multiplied by `window`. The first half of the samples is added
+/// to `overlap` and returned, replacing the coefficients in `input`. The
+/// second half is stored in `overlap` for the next block.
+///
+/// # Example
+///
+/// ```
+/// use microfft::mdct::imdct_65536;
+///
+/// let mut input = [0.; 32768];
+/// let window = [1.; 65536];
+/// let mut overlap = [0.; 32768];
+/// let result = imdct_65536(&mut input, &window, &mut overlap);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` or `overlap` has a length other than `32768`, or if
+/// `window` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn imdct_65536<'a>(input: &'a mut [f32], window: &[f32], overlap: &mut [f32]) -> &'a mut [f32] {
+    assert_eq!(input.len(), 32768);
+    assert_eq!(window.len(), 65536);
+    assert_eq!(overlap.len(), 32768);
+    imdct::<CFftN16384>(input, window, overlap)
+}
@@ -0,0 +1,214 @@
+use crate::{cfft::MAX_N, complex::try_cfft, math, permute::permute, FftError, Scaling};
+use num_complex::Complex32;
+
+/// Compute the CFFT of an input of size `2^a * 3^b * 5^c`.
+pub(crate) fn transform(x: &mut [Complex32]) -> Result<(), FftError> {
+    let factors = factorize(x.len())?;
+    compute(x, factors);
+    Ok(())
+}
+
+/// Compute the inverse CFFT of an input of size `2^a * 3^b * 5^c`.
+pub(crate) fn inverse_transform(x: &mut [Complex32], scaling: Scaling) -> Result<(), FftError> {
+    let factors = factorize(x.len())?;
+
+    // the inverse FFT is the conjugate of the FFT of the conjugated input
+    x.iter_mut().for_each(|c| *c = c.conj());
+    compute(x, factors);
+
+    let scale = match scaling {
+        Scaling::Unscaled => 1.,
+        Scaling::Normalized => 1. / x.len() as f32,
+    };
+    x.iter_mut().for_each(|c| *c = c.conj() * scale);
+    Ok(())
+}
+
+/// Factorization of a supported input size.
+#[derive(Clone, Copy)]
+struct Factors {
+    pow2: usize,
+    threes: usize,
+    fives: usize,
+}
+
+/// Compute the CFFT of an input of the given factorization.
+///
+/// The radix-3 and radix-5 stages are computed first, by decimation in
+/// frequency. They leave contiguous blocks of size `2^a` that are then
+/// transformed by the power-of-two CFFT. Finally, the output is reordered
+/// in-place from digit-reversed into natural order.
+fn compute(x: &mut [Complex32], factors: Factors) {
+    let Factors {
+        pow2,
+        threes,
+        fives,
+    } = factors;
+
+    let mut len = x.len();
+    for radix in Radices::new(threes, fives) {
+        for block in x.chunks_exact_mut(len) {
+            compute_stage(block, radix);
+        }
+        len /= radix;
+    }
+    debug_assert_eq!(len, pow2);
+
+    if pow2 > 1 {
+        for block in x.chunks_exact_mut(pow2) {
+            let result = try_cfft(block);
+            debug_assert!(result.is_ok());
+        }
+    }
+
+    if threes + fives > 0 {
+        digit_reverse_reorder(x, factors);
+    }
+}
+
+/// Split `n` into its power-of-two factor and the exponents of 3 and 5.
+fn factorize(n: usize) -> Result<Factors, FftError> {
+    if n < 2 {
+        return Err(FftError::TooSmall);
+    }
+
+    let mut rest = n;
+    let mut count = |factor| {
+        let mut exp = 0;
+        while rest / factor * factor == rest {
+            rest /= factor;
+            exp += 1;
+        }
+        exp
+    };
+    let threes = count(3);
+    let fives = count(5);
+
+    if !rest.is_power_of_two() {
+        return Err(FftError::UnsupportedFactor);
+    }
+
+    if rest > MAX_N {
+        return Err(FftError::TooLarge);
+    }
+    Ok(Factors {
+        pow2: rest,
+        threes,
+        fives,
+    })
+}
+
+/// Iterator over the odd radices, in the order the stages are computed.
+struct Radices {
+    threes: usize,
+    fives: usize,
+}
+
+impl Radices {
+    fn new(threes: usize, fives: usize) -> Self {
+        Self { threes, fives }
+    }
+}
+
+impl Iterator for Radices {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.threes > 0 {
+            self.threes -= 1;
+            Some(3)
+        } else if self.fives > 0 {
+            self.fives -= 1;
+            Some(5)
+        } else {
+            None
+        }
+    }
+}
+
+/// Compute a decimation-in-frequency stage of the given radix on a block.
+///
+/// Afterwards, the `p`-th part of the block holds the input to the FFT that
+/// yields the output values with indices `p + radix * k`.
+fn compute_stage(x: &mut [Complex32], radix: usize) {
+    let len = x.len();
+    let m = len / radix;
+
+    let mut y = [Complex32::default(); 5];
+    for j in 0..m {
+        for (q, y_q) in y[..radix].iter_mut().enumerate() {
+            *y_q = x[j + q * m];
+        }
+
+        match radix {
+            3 => butterfly_3(&mut y),
+            _ => butterfly_5(&mut y),
+        }
+
+        x[j] = y[0];
+        for (p, y_p) in y[..radix].iter().enumerate().skip(1) {
+            let (cos, sin) = math::cos_sin(j * p, len);
+            x[j + p * m] = y_p * Complex32::new(cos, -sin);
+        }
+    }
+}
+
+fn butterfly_3(y: &mut [Complex32; 5]) {
+    // sin(2π/3)
+    const S: f32 = 0.866_025_4;
+
+    let (a, b, c) = (y[0], y[1], y[2]);
+    let sum = b + c;
+    let diff = (b - c) * S;
+    let t = a - sum * 0.5;
+
+    y[0] = a + sum;
+    y[1] = Complex32::new(t.re + diff.im, t.im - diff.re);
+    y[2] = Complex32::new(t.re - diff.im, t.im + diff.re);
+}
+
+fn butterfly_5(y: &mut [Complex32; 5]) {
+    // cos(2π/5), cos(4π/5), sin(2π/5), sin(4π/5)
+    const C1: f32 = 0.309_017;
+    const C2: f32 = -0.809_017;
+    const S1: f32 = 0.951_056_5;
+    const S2: f32 = 0.587_785_24;
+
+    let a = y[0];
+    let (t1, t2) = (y[1] + y[4], y[1] - y[4]);
+    let (t3, t4) = (y[2] + y[3], y[2] - y[3]);
+
+    let re_1 = a + t1 * C1 + t3 * C2;
+    let im_1 = t2 * S1 + t4 * S2;
+    let re_2 = a + t1 * C2 + t3 * C1;
+    let im_2 = t2 * S2 - t4 * S1;
+
+    // `re_* ∓ i * im_*`
+    y[0] = a + t1 + t3;
+    y[1] = Complex32::new(re_1.re + im_1.im, re_1.im - im_1.re);
+    y[4] = Complex32::new(re_1.re - im_1.im, re_1.im + im_1.re);
+    y[2] = Complex32::new(re_2.re + im_2.im, re_2.im - im_2.re);
+    y[3] = Complex32::new(re_2.re - im_2.im, re_2.im + im_2.re);
+}
+
+/// Move the output values from digit-reversed into natural order.
+fn digit_reverse_reorder(x: &mut [Complex32], factors: Factors) {
+    let Factors {
+        pow2,
+        threes,
+        fives,
+    } = factors;
+
+    let target = |pos: usize| {
+        let mut rest = pos / pow2;
+        let mut index = pos % pow2;
+        let radices = Radices::new(0, fives).chain(Radices::new(threes, 0));
+        for radix in radices {
+            index = index * radix + rest % radix;
+            rest /= radix;
+        }
+        index
+    };
+
+    permute(x, target);
+}
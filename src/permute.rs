@@ -0,0 +1,31 @@
+//! In-place permutation of slices, used to bring transform outputs into
+//! natural order without a scratch buffer.
+
+use core::mem;
+
+/// Move each value `x[i]` to `x[target(i)]`.
+///
+/// The permutation is applied in-place by rotating each of its cycles, starting
+/// from the smallest index in the cycle.
+pub(crate) fn permute<T: Copy>(x: &mut [T], target: impl Fn(usize) -> usize) {
+    for start in 0..x.len() {
+        // skip cycles that were already rotated
+        let mut pos = target(start);
+        while pos > start {
+            pos = target(pos);
+        }
+        if pos < start {
+            continue;
+        }
+
+        let mut value = x[start];
+        let mut pos = start;
+        loop {
+            pos = target(pos);
+            mem::swap(&mut value, &mut x[pos]);
+            if pos == start {
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Power spectral density (PSD) estimation
+//!
+//! Welch's method estimates the PSD of a signal by splitting it into
+//! overlapping segments, computing the power spectrum of each windowed
+//! segment and averaging the results. Averaging reduces the variance of the
+//! estimate, while the overlap compensates for the samples attenuated by the
+//! window.
+//!
+//! The estimate is one-sided: The negative-frequency terms are folded into
+//! the positive-frequency ones, so all bins except for DC and Nyquist are
+//! doubled. It is normalized by the sample rate and the energy of the window,
+//! yielding values in units²/Hz. Integrating the PSD over frequency, i.e.
+//! summing all bins and multiplying by the bin width, yields an estimate of
+//! the mean power of the signal.
+
+use crate::{rfft::RFftArray, window::Window};
+
+/// Welch PSD estimator for segments of `N` samples.
+///
+/// The estimator keeps the window and the number of averaged segments. The
+/// averaged PSD of `N/2 + 1` bins is stored in a buffer provided by the
+/// caller, which always holds the average of all segments pushed so far.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{psd::Welch, window::Window};
+///
+/// // white noise with a variance of 1/3, sampled at 1 kHz
+/// let mut state = 1_u32;
+/// let signal: Vec<f32> = (0..4096)
+///     .map(|_| {
+///         state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+///         (state >> 8) as f32 / (1 << 23) as f32 - 1.
+///     })
+///     .collect();
+///
+/// let mut welch = Welch::<256>::new(Window::Hann, 1000.);
+/// let mut psd = [0.; 129];
+/// let segments = welch.push_overlapping(&signal, 128, &mut psd);
+/// assert_eq!(segments, 31);
+///
+/// // the noise is spread evenly over 500 Hz
+/// let mean = psd.iter().sum::<f32>() / psd.len() as f32;
+/// assert!((mean - 1. / 3. / 500.).abs() < 1e-4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Welch<const N: usize> {
+    window: [f32; N],
+    sample_rate: f32,
+    scale: f32,
+    segments: usize,
+}
+
+impl<const N: usize> Welch<N>
+where
+    [f32; N]: RFftArray,
+{
+    /// Create an estimator using the given window and sample rate in Hz.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sample_rate` is not positive.
+    pub fn new(window: Window, sample_rate: f32) -> Self {
+        assert!(sample_rate > 0., "sample rate must be positive");
+
+        let mut values = [0.; N];
+        for (i, w) in values.iter_mut().enumerate() {
+            *w = window.value(i, N);
+        }
+        let energy: f32 = values.iter().map(|w| w * w).sum();
+
+        Self {
+            window: values,
+            sample_rate,
+            scale: 1. / (sample_rate * energy),
+            segments: 0,
+        }
+    }
+
+    /// Add the power spectrum of a segment of `N` samples to the average in
+    /// `psd`.
+    ///
+    /// The previous contents of `psd` are ignored when pushing the first
+    /// segment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `segment` has a length other than `N`, or if `psd` has a
+    /// length other than `N/2 + 1`.
+    pub fn push(&mut self, segment: &[f32], psd: &mut [f32]) {
+        assert_eq!(segment.len(), N);
+        assert_eq!(psd.len(), N / 2 + 1);
+
+        let mut buffer = [0.; N];
+        for ((b, x), w) in buffer.iter_mut().zip(segment).zip(&self.window) {
+            *b = x * w;
+        }
+
+        // the spectrum is stored in-place, with the Nyquist term in the
+        // imaginary part of the DC value
+        buffer.transform_packed();
+
+        self.segments += 1;
+        let weight = 1. / self.segments as f32;
+        let mut accumulate = |k: usize, power: f32| {
+            let power = power * self.scale;
+            if self.segments == 1 {
+                psd[k] = power;
+            } else {
+                psd[k] += (power - psd[k]) * weight;
+            }
+        };
+
+        accumulate(0, buffer[0] * buffer[0]);
+        accumulate(N / 2, buffer[1] * buffer[1]);
+        for k in 1..N / 2 {
+            let (re, im) = (buffer[2 * k], buffer[2 * k + 1]);
+            accumulate(k, 2. * (re * re + im * im));
+        }
+    }
+
+    /// Add all segments of `signal` that overlap by `overlap` samples to the
+    /// average in `psd`, returning the number of segments.
+    ///
+    /// The segments start at multiples of `N - overlap`. Samples after the
+    /// last complete segment are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `overlap` is not less than `N`, or if `psd` has a length
+    /// other than `N/2 + 1`.
+    pub fn push_overlapping(&mut self, signal: &[f32], overlap: usize, psd: &mut [f32]) -> usize {
+        assert!(overlap < N, "overlap must be less than the segment size");
+
+        let mut count = 0;
+        for segment in signal.windows(N).step_by(N - overlap) {
+            self.push(segment, psd);
+            count += 1;
+        }
+        count
+    }
+
+    /// Return the number of segments averaged so far.
+    pub fn segments(&self) -> usize {
+        self.segments
+    }
+
+    /// Return the center frequency of bin `k` in Hz.
+    pub fn frequency(&self, k: usize) -> f32 {
+        k as f32 * self.sample_rate / N as f32
+    }
+
+    /// Start a new average.
+    ///
+    /// The next pushed segment replaces the contents of the PSD buffer.
+    pub fn reset(&mut self) {
+        self.segments = 0;
+    }
+}
@@ -10,10 +10,396 @@
 //! `N/2 - 1` positive-frequency terms. The negative-frequency terms
 //! are not computed, since they can be calculated from the
 //! positive-frequency terms and are therefore redundant.
+//!
+//! Both the DC and the Nyquist term of the spectrum are real-valued. The
+//! packed RFFT (`rfft_packed_*`) makes use of this to preserve the Nyquist
+//! term without requiring additional space: It produces the same output as
+//! the plain RFFT, except that the imaginary part of the DC value is replaced
+//! by the (real) Nyquist term. The plain RFFT drops the Nyquist term and
+//! always sets this value to zero.
+//!
+//! The inverse RFFT (`irfft_*`) reverses this process, reconstructing the
+//! `N` real values from `N/2` complex spectrum values in-place. It expects
+//! its input in the packed format. The output is scaled by `1/N`, so an
+//! inverse RFFT exactly undoes the corresponding packed RFFT. Applied to the
+//! output of a plain RFFT, the Nyquist component of the original signal is
+//! lost.
+//!
+//! For inputs whose size is known at compile time, the generic [`rfft`]
+//! function can be used instead of the size-specific ones. It takes a
+//! fixed-size array and fails to compile if the array size is not supported.
+//! The supported array types implement the [`RFftArray`] trait, which can be
+//! used as a bound in code that is generic over the array size.
+//! If the input size is only known at runtime, [`try_rfft`] dispatches to
+//! the matching size-specific function and returns an error if the size is
+//! not supported.
+//!
+//! The size marker types (`RFftN*`) implement the [`Fft`] trait, for
+//! writing code that is generic over the FFT size.
+//!
+//! [`Fft`]: ../trait.Fft.html
+//! [`RFftArray`]: trait.RFftArray.html
+//! [`rfft`]: fn.rfft.html
+//! [`try_rfft`]: fn.try_rfft.html
 
-use crate::rfft::*;
+use crate::{rfft::*, FftError};
 use num_complex::Complex32;
 
+pub use crate::rfft::{RFftArray, RFftN2, RFftN4};
+
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN8;
+
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN16;
+
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN32;
+
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN64;
+
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN128;
+
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN256;
+
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN512;
+
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN1024;
+
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN2048;
+
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN4096;
+
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+pub use crate::rfft::RFftN8192;
+
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+pub use crate::rfft::RFftN16384;
+
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+pub use crate::rfft::RFftN32768;
+
+#[cfg(feature = "maxn-65536")]
+pub use crate::rfft::RFftN65536;
+
+/// Perform an in-place RFFT on a fixed-size array.
+///
+/// The FFT size is inferred from the size of the input array. Passing an
+/// array whose size is not a power of two or exceeds the size selected by the
+/// `maxn-*` features results in a compile error.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::rfft, Complex32};
+///
+/// let mut input = [0.; 16];
+/// let result: &mut [Complex32; 8] = rfft(&mut input);
+/// ```
+///
+/// Unsupported sizes are rejected at compile time:
+///
+/// ```compile_fail
+/// use microfft::real::rfft;
+///
+/// let mut input = [0.; 12];
+/// let result = rfft(&mut input);
+/// ```
+#[inline]
+pub fn rfft<const N: usize>(input: &mut [f32; N]) -> &mut <[f32; N] as RFftArray>::Output
+where
+    [f32; N]: RFftArray,
+{
+    input.transform()
+}
+
+/// Perform an in-place RFFT on an input of runtime size.
+///
+/// The FFT size is determined by the length of the input slice.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::try_rfft, FftError};
+///
+/// let mut input = [0.; 16];
+/// let result = try_rfft(&mut input);
+/// assert!(result.is_ok());
+///
+/// let mut input = [0.; 12];
+/// let result = try_rfft(&mut input);
+/// assert_eq!(result, Err(FftError::NotPowerOfTwo));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if the length of `input` is not a supported FFT size.
+/// Inputs with fewer than two values, including empty ones, are reported as
+/// `FftError::TooSmall`.
+pub fn try_rfft(input: &mut [f32]) -> Result<&mut [Complex32], FftError> {
+    let result = match input.len() {
+        2 => rfft_2(input),
+        4 => rfft_4(input),
+        #[cfg(any(
+            feature = "maxn-8",
+            feature = "maxn-16",
+            feature = "maxn-32",
+            feature = "maxn-64",
+            feature = "maxn-128",
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        8 => rfft_8(input),
+        #[cfg(any(
+            feature = "maxn-16",
+            feature = "maxn-32",
+            feature = "maxn-64",
+            feature = "maxn-128",
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        16 => rfft_16(input),
+        #[cfg(any(
+            feature = "maxn-32",
+            feature = "maxn-64",
+            feature = "maxn-128",
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        32 => rfft_32(input),
+        #[cfg(any(
+            feature = "maxn-64",
+            feature = "maxn-128",
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        64 => rfft_64(input),
+        #[cfg(any(
+            feature = "maxn-128",
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        128 => rfft_128(input),
+        #[cfg(any(
+            feature = "maxn-256",
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        256 => rfft_256(input),
+        #[cfg(any(
+            feature = "maxn-512",
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        512 => rfft_512(input),
+        #[cfg(any(
+            feature = "maxn-1024",
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        1024 => rfft_1024(input),
+        #[cfg(any(
+            feature = "maxn-2048",
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        2048 => rfft_2048(input),
+        #[cfg(any(
+            feature = "maxn-4096",
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        4096 => rfft_4096(input),
+        #[cfg(any(
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        8192 => rfft_8192(input),
+        #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+        16384 => rfft_16384(input),
+        #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+        32768 => rfft_32768(input),
+        #[cfg(feature = "maxn-65536")]
+        65536 => rfft_65536(input),
+        n => return Err(FftError::for_size(n)),
+    };
+    Ok(result)
+}
+
 /// Perform an in-place 2-point RFFT.
 ///
 /// # Example
@@ -79,6 +465,10 @@ pub fn rfft_4(input: &mut [f32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn rfft_8(input: &mut [f32]) -> &mut [Complex32] {
@@ -110,6 +500,10 @@ pub fn rfft_8(input: &mut [f32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn rfft_16(input: &mut [f32]) -> &mut [Complex32] {
@@ -140,6 +534,10 @@ pub fn rfft_16(input: &mut [f32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn rfft_32(input: &mut [f32]) -> &mut [Complex32] {
@@ -169,6 +567,10 @@ pub fn rfft_32(input: &mut [f32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn rfft_64(input: &mut [f32]) -> &mut [Complex32] {
@@ -197,6 +599,10 @@ pub fn rfft_64(input: &mut [f32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn rfft_128(input: &mut [f32]) -> &mut [Complex32] {
@@ -224,6 +630,10 @@ pub fn rfft_128(input: &mut [f32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn rfft_256(input: &mut [f32]) -> &mut [Complex32] {
@@ -250,6 +660,10 @@ pub fn rfft_256(input: &mut [f32]) -> &mut [Complex32] {
     feature = "maxn-1024",
     feature = "maxn-2048",
     feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
 ))]
 #[inline]
 pub fn rfft_512(input: &mut [f32]) -> &mut [Complex32] {
@@ -271,7 +685,15 @@ pub fn rfft_512(input: &mut [f32]) -> &mut [Complex32] {
 /// # Panics
 ///
 /// Panics if `input` has a length other than `1024`.
-#[cfg(any(feature = "maxn-1024", feature = "maxn-2048", feature = "maxn-4096"))]
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
 #[inline]
 pub fn rfft_1024(input: &mut [f32]) -> &mut [Complex32] {
     assert_eq!(input.len(), 1024);
@@ -292,7 +714,14 @@ pub fn rfft_1024(input: &mut [f32]) -> &mut [Complex32] {
 /// # Panics
 ///
 /// Panics if `input` has a length other than `2048`.
-#[cfg(any(feature = "maxn-2048", feature = "maxn-4096"))]
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
 #[inline]
 pub fn rfft_2048(input: &mut [f32]) -> &mut [Complex32] {
     assert_eq!(input.len(), 2048);
@@ -313,9 +742,1008 @@ pub fn rfft_2048(input: &mut [f32]) -> &mut [Complex32] {
 /// # Panics
 ///
 /// Panics if `input` has a length other than `4096`.
-#[cfg(any(feature = "maxn-4096"))]
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
 #[inline]
 pub fn rfft_4096(input: &mut [f32]) -> &mut [Complex32] {
     assert_eq!(input.len(), 4096);
     RFftN4096::transform(input)
 }
+
+/// Perform an in-place 8192-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = rfft_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_8192(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 8192);
+    RFftN8192::transform(input)
+}
+
+/// Perform an in-place 16384-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = rfft_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn rfft_16384(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 16384);
+    RFftN16384::transform(input)
+}
+
+/// Perform an in-place 32768-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = rfft_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn rfft_32768(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 32768);
+    RFftN32768::transform(input)
+}
+
+/// Perform an in-place 65536-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = rfft_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn rfft_65536(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 65536);
+    RFftN65536::transform(input)
+}
+
+/// Perform an in-place 2-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_2;
+///
+/// let mut input = [0.; 2];
+/// let result = rfft_packed_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn rfft_packed_2(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 2);
+    RFftN2::transform_packed(input)
+}
+
+/// Perform an in-place 4-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_4;
+///
+/// let mut input = [0.; 4];
+/// let result = rfft_packed_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn rfft_packed_4(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 4);
+    RFftN4::transform_packed(input)
+}
+
+/// Perform an in-place 8-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_8;
+///
+/// let mut input = [0.; 8];
+/// let result = rfft_packed_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_8(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 8);
+    RFftN8::transform_packed(input)
+}
+
+/// Perform an in-place 16-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_16;
+///
+/// let mut input = [0.; 16];
+/// let result = rfft_packed_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_16(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 16);
+    RFftN16::transform_packed(input)
+}
+
+/// Perform an in-place 32-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_32;
+///
+/// let mut input = [0.; 32];
+/// let result = rfft_packed_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_32(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 32);
+    RFftN32::transform_packed(input)
+}
+
+/// Perform an in-place 64-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_64;
+///
+/// let mut input = [0.; 64];
+/// let result = rfft_packed_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_64(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 64);
+    RFftN64::transform_packed(input)
+}
+
+/// Perform an in-place 128-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_128;
+///
+/// let mut input = [0.; 128];
+/// let result = rfft_packed_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_128(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 128);
+    RFftN128::transform_packed(input)
+}
+
+/// Perform an in-place 256-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_256;
+///
+/// let mut input = [0.; 256];
+/// let result = rfft_packed_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_256(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 256);
+    RFftN256::transform_packed(input)
+}
+
+/// Perform an in-place 512-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_512;
+///
+/// let mut input = [0.; 512];
+/// let result = rfft_packed_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_512(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 512);
+    RFftN512::transform_packed(input)
+}
+
+/// Perform an in-place 1024-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_1024;
+///
+/// let mut input = [0.; 1024];
+/// let result = rfft_packed_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_1024(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 1024);
+    RFftN1024::transform_packed(input)
+}
+
+/// Perform an in-place 2048-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_2048;
+///
+/// let mut input = [0.; 2048];
+/// let result = rfft_packed_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_2048(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 2048);
+    RFftN2048::transform_packed(input)
+}
+
+/// Perform an in-place 4096-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_4096;
+///
+/// let mut input = [0.; 4096];
+/// let result = rfft_packed_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_4096(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 4096);
+    RFftN4096::transform_packed(input)
+}
+
+/// Perform an in-place 8192-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = rfft_packed_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_8192(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 8192);
+    RFftN8192::transform_packed(input)
+}
+
+/// Perform an in-place 16384-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = rfft_packed_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn rfft_packed_16384(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 16384);
+    RFftN16384::transform_packed(input)
+}
+
+/// Perform an in-place 32768-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = rfft_packed_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn rfft_packed_32768(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 32768);
+    RFftN32768::transform_packed(input)
+}
+
+/// Perform an in-place 65536-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::rfft_packed_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = rfft_packed_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn rfft_packed_65536(input: &mut [f32]) -> &mut [Complex32] {
+    assert_eq!(input.len(), 65536);
+    RFftN65536::transform_packed(input)
+}
+
+/// Perform an in-place 2-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_2, Complex32};
+///
+/// let mut input = [Complex32::default(); 1];
+/// let result = irfft_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1`.
+#[inline]
+pub fn irfft_2(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 1);
+    RFftN2::inverse_transform(input)
+}
+
+/// Perform an in-place 4-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_4, Complex32};
+///
+/// let mut input = [Complex32::default(); 2];
+/// let result = irfft_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn irfft_4(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2);
+    RFftN4::inverse_transform(input)
+}
+
+/// Perform an in-place 8-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_8, Complex32};
+///
+/// let mut input = [Complex32::default(); 4];
+/// let result = irfft_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_8(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4);
+    RFftN8::inverse_transform(input)
+}
+
+/// Perform an in-place 16-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_16, Complex32};
+///
+/// let mut input = [Complex32::default(); 8];
+/// let result = irfft_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_16(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8);
+    RFftN16::inverse_transform(input)
+}
+
+/// Perform an in-place 32-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_32, Complex32};
+///
+/// let mut input = [Complex32::default(); 16];
+/// let result = irfft_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_32(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16);
+    RFftN32::inverse_transform(input)
+}
+
+/// Perform an in-place 64-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_64, Complex32};
+///
+/// let mut input = [Complex32::default(); 32];
+/// let result = irfft_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_64(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32);
+    RFftN64::inverse_transform(input)
+}
+
+/// Perform an in-place 128-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_128, Complex32};
+///
+/// let mut input = [Complex32::default(); 64];
+/// let result = irfft_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_128(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 64);
+    RFftN128::inverse_transform(input)
+}
+
+/// Perform an in-place 256-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_256, Complex32};
+///
+/// let mut input = [Complex32::default(); 128];
+/// let result = irfft_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_256(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 128);
+    RFftN256::inverse_transform(input)
+}
+
+/// Perform an in-place 512-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_512, Complex32};
+///
+/// let mut input = [Complex32::default(); 256];
+/// let result = irfft_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_512(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 256);
+    RFftN512::inverse_transform(input)
+}
+
+/// Perform an in-place 1024-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_1024, Complex32};
+///
+/// let mut input = [Complex32::default(); 512];
+/// let result = irfft_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_1024(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 512);
+    RFftN1024::inverse_transform(input)
+}
+
+/// Perform an in-place 2048-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_2048, Complex32};
+///
+/// let mut input = [Complex32::default(); 1024];
+/// let result = irfft_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_2048(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 1024);
+    RFftN2048::inverse_transform(input)
+}
+
+/// Perform an in-place 4096-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_4096, Complex32};
+///
+/// let mut input = [Complex32::default(); 2048];
+/// let result = irfft_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_4096(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 2048);
+    RFftN4096::inverse_transform(input)
+}
+
+/// Perform an in-place 8192-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_8192, Complex32};
+///
+/// let mut input = [Complex32::default(); 4096];
+/// let result = irfft_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_8192(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 4096);
+    RFftN8192::inverse_transform(input)
+}
+
+/// Perform an in-place 16384-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_16384, Complex32};
+///
+/// let mut input = [Complex32::default(); 8192];
+/// let result = irfft_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn irfft_16384(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 8192);
+    RFftN16384::inverse_transform(input)
+}
+
+/// Perform an in-place 32768-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_32768, Complex32};
+///
+/// let mut input = [Complex32::default(); 16384];
+/// let result = irfft_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn irfft_32768(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 16384);
+    RFftN32768::inverse_transform(input)
+}
+
+/// Perform an in-place 65536-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real::irfft_65536, Complex32};
+///
+/// let mut input = [Complex32::default(); 32768];
+/// let result = irfft_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn irfft_65536(input: &mut [Complex32]) -> &mut [f32] {
+    assert_eq!(input.len(), 32768);
+    RFftN65536::inverse_transform(input)
+}
@@ -0,0 +1,1359 @@
+//! FFT on double-precision real inputs (RFFT)
+//!
+//! This module provides the same plain (`rfft_*`), packed (`rfft_packed_*`)
+//! and inverse (`irfft_*`) transforms as the [`real`] module, operating on
+//! `f64` values instead. It is only available if the `f64` feature is
+//! enabled, which also adds a double-precision sine table.
+//!
+//! [`real`]: ../real/index.html
+
+use crate::rfft::*;
+use num_complex::Complex64;
+
+/// Perform an in-place 2-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_2;
+///
+/// let mut input = [0.; 2];
+/// let result = rfft_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn rfft_2(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 2);
+    RFftN2::transform(input)
+}
+
+/// Perform an in-place 4-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_4;
+///
+/// let mut input = [0.; 4];
+/// let result = rfft_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn rfft_4(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 4);
+    RFftN4::transform(input)
+}
+
+/// Perform an in-place 8-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_8;
+///
+/// let mut input = [0.; 8];
+/// let result = rfft_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_8(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 8);
+    RFftN8::transform(input)
+}
+
+/// Perform an in-place 16-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_16;
+///
+/// let mut input = [0.; 16];
+/// let result = rfft_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_16(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 16);
+    RFftN16::transform(input)
+}
+
+/// Perform an in-place 32-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_32;
+///
+/// let mut input = [0.; 32];
+/// let result = rfft_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_32(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 32);
+    RFftN32::transform(input)
+}
+
+/// Perform an in-place 64-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_64;
+///
+/// let mut input = [0.; 64];
+/// let result = rfft_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_64(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 64);
+    RFftN64::transform(input)
+}
+
+/// Perform an in-place 128-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_128;
+///
+/// let mut input = [0.; 128];
+/// let result = rfft_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_128(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 128);
+    RFftN128::transform(input)
+}
+
+/// Perform an in-place 256-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_256;
+///
+/// let mut input = [0.; 256];
+/// let result = rfft_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_256(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 256);
+    RFftN256::transform(input)
+}
+
+/// Perform an in-place 512-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_512;
+///
+/// let mut input = [0.; 512];
+/// let result = rfft_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_512(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 512);
+    RFftN512::transform(input)
+}
+
+/// Perform an in-place 1024-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_1024;
+///
+/// let mut input = [0.; 1024];
+/// let result = rfft_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_1024(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 1024);
+    RFftN1024::transform(input)
+}
+
+/// Perform an in-place 2048-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_2048;
+///
+/// let mut input = [0.; 2048];
+/// let result = rfft_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_2048(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 2048);
+    RFftN2048::transform(input)
+}
+
+/// Perform an in-place 4096-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_4096;
+///
+/// let mut input = [0.; 4096];
+/// let result = rfft_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_4096(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 4096);
+    RFftN4096::transform(input)
+}
+
+/// Perform an in-place 8192-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = rfft_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_8192(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 8192);
+    RFftN8192::transform(input)
+}
+
+/// Perform an in-place 16384-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = rfft_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn rfft_16384(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 16384);
+    RFftN16384::transform(input)
+}
+
+/// Perform an in-place 32768-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = rfft_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn rfft_32768(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 32768);
+    RFftN32768::transform(input)
+}
+
+/// Perform an in-place 65536-point RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = rfft_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn rfft_65536(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 65536);
+    RFftN65536::transform(input)
+}
+
+/// Perform an in-place 2-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_2;
+///
+/// let mut input = [0.; 2];
+/// let result = rfft_packed_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn rfft_packed_2(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 2);
+    RFftN2::transform_packed(input)
+}
+
+/// Perform an in-place 4-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_4;
+///
+/// let mut input = [0.; 4];
+/// let result = rfft_packed_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn rfft_packed_4(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 4);
+    RFftN4::transform_packed(input)
+}
+
+/// Perform an in-place 8-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_8;
+///
+/// let mut input = [0.; 8];
+/// let result = rfft_packed_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_8(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 8);
+    RFftN8::transform_packed(input)
+}
+
+/// Perform an in-place 16-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_16;
+///
+/// let mut input = [0.; 16];
+/// let result = rfft_packed_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_16(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 16);
+    RFftN16::transform_packed(input)
+}
+
+/// Perform an in-place 32-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_32;
+///
+/// let mut input = [0.; 32];
+/// let result = rfft_packed_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_32(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 32);
+    RFftN32::transform_packed(input)
+}
+
+/// Perform an in-place 64-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_64;
+///
+/// let mut input = [0.; 64];
+/// let result = rfft_packed_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_64(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 64);
+    RFftN64::transform_packed(input)
+}
+
+/// Perform an in-place 128-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_128;
+///
+/// let mut input = [0.; 128];
+/// let result = rfft_packed_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_128(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 128);
+    RFftN128::transform_packed(input)
+}
+
+/// Perform an in-place 256-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_256;
+///
+/// let mut input = [0.; 256];
+/// let result = rfft_packed_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_256(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 256);
+    RFftN256::transform_packed(input)
+}
+
+/// Perform an in-place 512-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_512;
+///
+/// let mut input = [0.; 512];
+/// let result = rfft_packed_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_512(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 512);
+    RFftN512::transform_packed(input)
+}
+
+/// Perform an in-place 1024-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_1024;
+///
+/// let mut input = [0.; 1024];
+/// let result = rfft_packed_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_1024(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 1024);
+    RFftN1024::transform_packed(input)
+}
+
+/// Perform an in-place 2048-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_2048;
+///
+/// let mut input = [0.; 2048];
+/// let result = rfft_packed_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_2048(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 2048);
+    RFftN2048::transform_packed(input)
+}
+
+/// Perform an in-place 4096-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_4096;
+///
+/// let mut input = [0.; 4096];
+/// let result = rfft_packed_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_4096(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 4096);
+    RFftN4096::transform_packed(input)
+}
+
+/// Perform an in-place 8192-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_8192;
+///
+/// let mut input = [0.; 8192];
+/// let result = rfft_packed_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_packed_8192(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 8192);
+    RFftN8192::transform_packed(input)
+}
+
+/// Perform an in-place 16384-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_16384;
+///
+/// let mut input = [0.; 16384];
+/// let result = rfft_packed_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn rfft_packed_16384(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 16384);
+    RFftN16384::transform_packed(input)
+}
+
+/// Perform an in-place 32768-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_32768;
+///
+/// let mut input = [0.; 32768];
+/// let result = rfft_packed_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn rfft_packed_32768(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 32768);
+    RFftN32768::transform_packed(input)
+}
+
+/// Perform an in-place 65536-point RFFT, storing the Nyquist term in the
+/// imaginary part of the DC value.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real64::rfft_packed_65536;
+///
+/// let mut input = [0.; 65536];
+/// let result = rfft_packed_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn rfft_packed_65536(input: &mut [f64]) -> &mut [Complex64] {
+    assert_eq!(input.len(), 65536);
+    RFftN65536::transform_packed(input)
+}
+
+/// Perform an in-place 2-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_2, Complex64};
+///
+/// let mut input = [Complex64::default(); 1];
+/// let result = irfft_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1`.
+#[inline]
+pub fn irfft_2(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 1);
+    RFftN2::inverse_transform(input)
+}
+
+/// Perform an in-place 4-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_4, Complex64};
+///
+/// let mut input = [Complex64::default(); 2];
+/// let result = irfft_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn irfft_4(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 2);
+    RFftN4::inverse_transform(input)
+}
+
+/// Perform an in-place 8-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_8, Complex64};
+///
+/// let mut input = [Complex64::default(); 4];
+/// let result = irfft_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_8(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 4);
+    RFftN8::inverse_transform(input)
+}
+
+/// Perform an in-place 16-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_16, Complex64};
+///
+/// let mut input = [Complex64::default(); 8];
+/// let result = irfft_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_16(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 8);
+    RFftN16::inverse_transform(input)
+}
+
+/// Perform an in-place 32-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_32, Complex64};
+///
+/// let mut input = [Complex64::default(); 16];
+/// let result = irfft_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_32(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 16);
+    RFftN32::inverse_transform(input)
+}
+
+/// Perform an in-place 64-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_64, Complex64};
+///
+/// let mut input = [Complex64::default(); 32];
+/// let result = irfft_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_64(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 32);
+    RFftN64::inverse_transform(input)
+}
+
+/// Perform an in-place 128-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_128, Complex64};
+///
+/// let mut input = [Complex64::default(); 64];
+/// let result = irfft_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_128(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 64);
+    RFftN128::inverse_transform(input)
+}
+
+/// Perform an in-place 256-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_256, Complex64};
+///
+/// let mut input = [Complex64::default(); 128];
+/// let result = irfft_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_256(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 128);
+    RFftN256::inverse_transform(input)
+}
+
+/// Perform an in-place 512-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_512, Complex64};
+///
+/// let mut input = [Complex64::default(); 256];
+/// let result = irfft_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_512(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 256);
+    RFftN512::inverse_transform(input)
+}
+
+/// Perform an in-place 1024-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_1024, Complex64};
+///
+/// let mut input = [Complex64::default(); 512];
+/// let result = irfft_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_1024(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 512);
+    RFftN1024::inverse_transform(input)
+}
+
+/// Perform an in-place 2048-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_2048, Complex64};
+///
+/// let mut input = [Complex64::default(); 1024];
+/// let result = irfft_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_2048(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 1024);
+    RFftN2048::inverse_transform(input)
+}
+
+/// Perform an in-place 4096-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_4096, Complex64};
+///
+/// let mut input = [Complex64::default(); 2048];
+/// let result = irfft_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_4096(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 2048);
+    RFftN4096::inverse_transform(input)
+}
+
+/// Perform an in-place 8192-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_8192, Complex64};
+///
+/// let mut input = [Complex64::default(); 4096];
+/// let result = irfft_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn irfft_8192(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 4096);
+    RFftN8192::inverse_transform(input)
+}
+
+/// Perform an in-place 16384-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_16384, Complex64};
+///
+/// let mut input = [Complex64::default(); 8192];
+/// let result = irfft_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn irfft_16384(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 8192);
+    RFftN16384::inverse_transform(input)
+}
+
+/// Perform an in-place 32768-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_32768, Complex64};
+///
+/// let mut input = [Complex64::default(); 16384];
+/// let result = irfft_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn irfft_32768(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 16384);
+    RFftN32768::inverse_transform(input)
+}
+
+/// Perform an in-place 65536-point inverse RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{real64::irfft_65536, Complex64};
+///
+/// let mut input = [Complex64::default(); 32768];
+/// let result = irfft_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn irfft_65536(input: &mut [Complex64]) -> &mut [f64] {
+    assert_eq!(input.len(), 32768);
+    RFftN65536::inverse_transform(input)
+}
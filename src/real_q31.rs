@@ -0,0 +1,461 @@
+//! FFT on Q31 fixed-point real inputs (RFFT)
+//!
+//! This module provides forward transforms on real values in the Q31
+//! fixed-point format, for targets without a floating-point unit. Input
+//! values are stored as `i32` values, representing numbers in the range
+//! `[-1, 1)`. The output format matches the one of the [`real`] module.
+//!
+//! To prevent overflows, intermediate results are halved in each stage. The
+//! resulting spectrum is therefore scaled by `1/N` compared to the output of
+//! the corresponding floating-point RFFT.
+//!
+//! This module is only available if the `q31` feature is enabled, which also
+//! adds a Q31 sine table.
+//!
+//! [`real`]: ../real/index.html
+
+use crate::{rfft::*, ComplexQ31};
+
+/// Perform an in-place 2-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_2;
+///
+/// let mut input = [0; 2];
+/// let result = rfft_2(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2`.
+#[inline]
+pub fn rfft_2(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 2);
+    RFftN2::transform_fixed(input)
+}
+
+/// Perform an in-place 4-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_4;
+///
+/// let mut input = [0; 4];
+/// let result = rfft_4(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4`.
+#[inline]
+pub fn rfft_4(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 4);
+    RFftN4::transform_fixed(input)
+}
+
+/// Perform an in-place 8-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_8;
+///
+/// let mut input = [0; 8];
+/// let result = rfft_8(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8`.
+#[cfg(any(
+    feature = "maxn-8",
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_8(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 8);
+    RFftN8::transform_fixed(input)
+}
+
+/// Perform an in-place 16-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_16;
+///
+/// let mut input = [0; 16];
+/// let result = rfft_16(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16`.
+#[cfg(any(
+    feature = "maxn-16",
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_16(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 16);
+    RFftN16::transform_fixed(input)
+}
+
+/// Perform an in-place 32-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_32;
+///
+/// let mut input = [0; 32];
+/// let result = rfft_32(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32`.
+#[cfg(any(
+    feature = "maxn-32",
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_32(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 32);
+    RFftN32::transform_fixed(input)
+}
+
+/// Perform an in-place 64-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_64;
+///
+/// let mut input = [0; 64];
+/// let result = rfft_64(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `64`.
+#[cfg(any(
+    feature = "maxn-64",
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_64(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 64);
+    RFftN64::transform_fixed(input)
+}
+
+/// Perform an in-place 128-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_128;
+///
+/// let mut input = [0; 128];
+/// let result = rfft_128(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `128`.
+#[cfg(any(
+    feature = "maxn-128",
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_128(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 128);
+    RFftN128::transform_fixed(input)
+}
+
+/// Perform an in-place 256-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_256;
+///
+/// let mut input = [0; 256];
+/// let result = rfft_256(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `256`.
+#[cfg(any(
+    feature = "maxn-256",
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_256(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 256);
+    RFftN256::transform_fixed(input)
+}
+
+/// Perform an in-place 512-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_512;
+///
+/// let mut input = [0; 512];
+/// let result = rfft_512(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `512`.
+#[cfg(any(
+    feature = "maxn-512",
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_512(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 512);
+    RFftN512::transform_fixed(input)
+}
+
+/// Perform an in-place 1024-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_1024;
+///
+/// let mut input = [0; 1024];
+/// let result = rfft_1024(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `1024`.
+#[cfg(any(
+    feature = "maxn-1024",
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_1024(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 1024);
+    RFftN1024::transform_fixed(input)
+}
+
+/// Perform an in-place 2048-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_2048;
+///
+/// let mut input = [0; 2048];
+/// let result = rfft_2048(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `2048`.
+#[cfg(any(
+    feature = "maxn-2048",
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_2048(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 2048);
+    RFftN2048::transform_fixed(input)
+}
+
+/// Perform an in-place 4096-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_4096;
+///
+/// let mut input = [0; 4096];
+/// let result = rfft_4096(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `4096`.
+#[cfg(any(
+    feature = "maxn-4096",
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_4096(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 4096);
+    RFftN4096::transform_fixed(input)
+}
+
+/// Perform an in-place 8192-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_8192;
+///
+/// let mut input = [0; 8192];
+/// let result = rfft_8192(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `8192`.
+#[cfg(any(
+    feature = "maxn-8192",
+    feature = "maxn-16384",
+    feature = "maxn-32768",
+    feature = "maxn-65536",
+))]
+#[inline]
+pub fn rfft_8192(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 8192);
+    RFftN8192::transform_fixed(input)
+}
+
+/// Perform an in-place 16384-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_16384;
+///
+/// let mut input = [0; 16384];
+/// let result = rfft_16384(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `16384`.
+#[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn rfft_16384(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 16384);
+    RFftN16384::transform_fixed(input)
+}
+
+/// Perform an in-place 32768-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_32768;
+///
+/// let mut input = [0; 32768];
+/// let result = rfft_32768(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `32768`.
+#[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+#[inline]
+pub fn rfft_32768(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 32768);
+    RFftN32768::transform_fixed(input)
+}
+
+/// Perform an in-place 65536-point Q31 RFFT.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real_q31::rfft_65536;
+///
+/// let mut input = [0; 65536];
+/// let result = rfft_65536(&mut input);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `input` has a length other than `65536`.
+#[cfg(feature = "maxn-65536")]
+#[inline]
+pub fn rfft_65536(input: &mut [i32]) -> &mut [ComplexQ31] {
+    assert_eq!(input.len(), 65536);
+    RFftN65536::transform_fixed(input)
+}
@@ -1,15 +1,52 @@
-use crate::{cfft::*, tables};
+#[cfg(any(feature = "q15", feature = "q31"))]
+use crate::fixed::Fixed;
+use crate::{cfft::*, float::Float, sealed::Sealed, Fft, Scaling};
 use core::slice;
-use num_complex::Complex32;
+use num_complex::{Complex, Complex32};
 use static_assertions::{assert_eq_align, assert_eq_size};
 
+assert_eq_size!(Complex32, [f32; 2]);
+assert_eq_align!(Complex32, f32);
+
+#[cfg(feature = "f64")]
+assert_eq_size!(num_complex::Complex64, [f64; 2]);
+#[cfg(feature = "f64")]
+assert_eq_align!(num_complex::Complex64, f64);
+
+#[cfg(feature = "q15")]
+assert_eq_size!(Complex<i16>, [i16; 2]);
+#[cfg(feature = "q15")]
+assert_eq_align!(Complex<i16>, i16);
+
+#[cfg(feature = "q31")]
+assert_eq_size!(Complex<i32>, [i32; 2]);
+#[cfg(feature = "q31")]
+assert_eq_align!(Complex<i32>, i32);
+
+/// Reinterpret `2n` values as `n` complex values.
+///
+/// The layout of `Complex<T>` matches `[T; 2]`, as asserted above for all
+/// value types used by microfft.
+#[inline]
+pub(crate) fn as_complex<T>(x: &mut [T]) -> &mut [Complex<T>] {
+    let data = x.as_mut_ptr().cast::<Complex<T>>();
+    unsafe { slice::from_raw_parts_mut(data, x.len() / 2) }
+}
+
+/// Reinterpret `n` complex values as `2n` values.
+#[inline]
+pub(crate) fn as_real<T>(x: &mut [Complex<T>]) -> &mut [T] {
+    let data = x.as_mut_ptr().cast::<T>();
+    unsafe { slice::from_raw_parts_mut(data, x.len() * 2) }
+}
+
 pub(crate) trait RFft {
     type CFft: CFft;
 
     const N: usize = Self::CFft::N * 2;
 
     #[inline]
-    fn transform(x: &mut [f32]) -> &mut [Complex32] {
+    fn transform<T: Float>(x: &mut [T]) -> &mut [Complex<T>] {
         debug_assert_eq!(x.len(), Self::N);
 
         let x = Self::pack_complex(x);
@@ -20,72 +57,196 @@ pub(crate) trait RFft {
     }
 
     #[inline]
-    fn pack_complex(x: &mut [f32]) -> &mut [Complex32] {
-        assert_eq_size!(Complex32, [f32; 2]);
-        assert_eq_align!(Complex32, f32);
+    fn transform_packed<T: Float>(x: &mut [T]) -> &mut [Complex<T>] {
+        debug_assert_eq!(x.len(), Self::N);
+
+        let x = Self::pack_complex(x);
+
+        Self::CFft::transform(x);
+
+        // `recombine` drops the Nyquist term, so compute it beforehand
+        let nyquist = x[0].re - x[0].im;
+        Self::recombine(x);
+        x[0].im = nyquist;
+        x
+    }
+
+    #[cfg(any(feature = "q15", feature = "q31"))]
+    #[inline]
+    fn transform_fixed<T: Fixed>(x: &mut [T]) -> &mut [Complex<T>] {
+        debug_assert_eq!(x.len(), Self::N);
+
+        let x = Self::pack_complex(x);
+
+        Self::CFft::transform_fixed(x);
+        Self::recombine_fixed(x);
+        x
+    }
+
+    #[inline]
+    fn inverse_transform<T: Float>(x: &mut [Complex<T>]) -> &mut [T] {
+        debug_assert_eq!(x.len(), Self::N / 2);
+
+        Self::inverse_recombine(x);
+        Self::CFft::inverse_transform(x, Scaling::Normalized);
+        Self::unpack_real(x)
+    }
+
+    #[inline]
+    fn pack_complex<T>(x: &mut [T]) -> &mut [Complex<T>] {
         assert_eq!(x.len(), Self::N);
+        as_complex(x)
+    }
 
-        let len = Self::N / 2;
-        let data = x.as_mut_ptr().cast::<Complex32>();
-        unsafe { slice::from_raw_parts_mut(data, len) }
+    #[inline]
+    fn unpack_real<T>(x: &mut [Complex<T>]) -> &mut [T] {
+        assert_eq!(x.len(), Self::N / 2);
+        as_real(x)
     }
 
     #[inline]
-    fn recombine(x: &mut [Complex32]) {
+    fn recombine<T: Float>(x: &mut [Complex<T>]) {
         let m = Self::CFft::N;
         debug_assert_eq!(x.len(), m);
 
-        let table_len = tables::SINE.len();
+        let table_len = T::SINE.len();
         let table_stride = (table_len + 1) * 4 / Self::N;
+        let two = T::one() + T::one();
 
         // DC
         let x0 = x[0];
-        x[0] = Complex32::new(x0.re + x0.im, 0.);
+        x[0] = Complex::new(x0.re + x0.im, T::zero());
 
         let u = m / 2;
         for k in 1..u {
             let s = k * table_stride;
-            let twiddle_re = tables::SINE[table_len - s] * -1.;
-            let twiddle_im = tables::SINE[s - 1];
+            let twiddle_re = -T::SINE[table_len - s];
+            let twiddle_im = T::SINE[s - 1];
 
             let (x_k, x_nk) = (x[k], x[m - k]);
-            let sum = (x_k + x_nk) / 2.;
-            let diff = (x_k - x_nk) / 2.;
+            let sum = (x_k + x_nk) / two;
+            let diff = (x_k - x_nk) / two;
 
-            x[k] = Complex32::new(
+            x[k] = Complex::new(
                 sum.re + twiddle_re * sum.im + twiddle_im * diff.re,
                 diff.im + twiddle_im * sum.im - twiddle_re * diff.re,
             );
-            x[m - k] = Complex32::new(
+            x[m - k] = Complex::new(
                 sum.re - twiddle_re * sum.im - twiddle_im * diff.re,
                 -diff.im + twiddle_im * sum.im - twiddle_re * diff.re,
             );
         }
 
-        x[u] *= Complex32::new(0., -1.);
+        x[u] = x[u].conj();
+    }
+
+    // Same as `recombine`, except that the output is halved to prevent
+    // overflows.
+    #[cfg(any(feature = "q15", feature = "q31"))]
+    #[inline]
+    fn recombine_fixed<T: Fixed>(x: &mut [Complex<T>]) {
+        let m = Self::CFft::N;
+        debug_assert_eq!(x.len(), m);
+
+        let table_len = T::SINE.len();
+        let table_stride = (table_len + 1) * 4 / Self::N;
+
+        // DC
+        let x0 = x[0];
+        x[0] = Complex::new(T::half_add(x0.re, x0.im), T::default());
+
+        let u = m / 2;
+        for k in 1..u {
+            let s = k * table_stride;
+            let twiddle = Complex::new(T::SINE[table_len - s].neg(), T::SINE[s - 1]);
+
+            let (x_k, x_nk) = (x[k], x[m - k]);
+            let even = Complex::new(T::half_add(x_k.re, x_nk.re), T::half_sub(x_k.im, x_nk.im));
+            let odd = Complex::new(T::half_add(x_k.im, x_nk.im), T::half_sub(x_nk.re, x_k.re));
+            let y = T::mul(odd, twiddle);
+
+            x[k] = T::half_sum(even, y);
+            let diff = T::half_diff(even, y);
+            x[m - k] = Complex::new(diff.re, diff.im.neg());
+        }
+
+        let x_u = x[u];
+        x[u] = Complex::new(x_u.re.half(), x_u.im.neg().half());
+    }
+
+    #[inline]
+    fn inverse_recombine<T: Float>(x: &mut [Complex<T>]) {
+        let m = Self::CFft::N;
+        debug_assert_eq!(x.len(), m);
+
+        let table_len = T::SINE.len();
+        let table_stride = (table_len + 1) * 4 / Self::N;
+        let two = T::one() + T::one();
+
+        // DC and Nyquist
+        let x0 = x[0];
+        x[0] = Complex::new(x0.re + x0.im, x0.re - x0.im) / two;
+
+        let u = m / 2;
+        for k in 1..u {
+            // conjugated twiddle factor
+            let s = k * table_stride;
+            let twiddle = Complex::new(-T::SINE[table_len - s], -T::SINE[s - 1]);
+
+            let (x_k, x_nk) = (x[k], x[m - k].conj());
+            let even = (x_k + x_nk) / two;
+            let odd = (x_k - x_nk) / two * twiddle;
+
+            x[k] = even + odd * Complex::i();
+            x[m - k] = even.conj() + odd.conj() * Complex::i();
+        }
+
+        x[u] = x[u].conj();
     }
 }
 
-pub(crate) struct RFftN2;
+/// Size marker type of the 2-point RFFT.
+pub struct RFftN2;
 
 impl RFft for RFftN2 {
     type CFft = CFftN1;
 
     #[inline]
-    fn recombine(x: &mut [Complex32]) {
+    fn recombine<T: Float>(x: &mut [Complex<T>]) {
         debug_assert_eq!(x.len(), 1);
 
         // DC
         let x0 = x[0];
-        x[0] = Complex32::new(x0.re + x0.im, 0.);
+        x[0] = Complex::new(x0.re + x0.im, T::zero());
+    }
+
+    #[cfg(any(feature = "q15", feature = "q31"))]
+    #[inline]
+    fn recombine_fixed<T: Fixed>(x: &mut [Complex<T>]) {
+        debug_assert_eq!(x.len(), 1);
+
+        // DC
+        let x0 = x[0];
+        x[0] = Complex::new(T::half_add(x0.re, x0.im), T::default());
+    }
+
+    #[inline]
+    fn inverse_recombine<T: Float>(x: &mut [Complex<T>]) {
+        debug_assert_eq!(x.len(), 1);
+
+        // DC and Nyquist
+        let two = T::one() + T::one();
+        let x0 = x[0];
+        x[0] = Complex::new(x0.re + x0.im, x0.re - x0.im) / two;
     }
 }
 
 macro_rules! rfft_impls {
     ( $( ($RFftN:ident, $CFftN:ident), )* ) => {
         $(
+            /// Size marker type of an RFFT.
             #[allow(dead_code)]
-            pub(crate) struct $RFftN;
+            pub struct $RFftN;
 
             impl RFft for $RFftN {
                 type CFft = $CFftN;
@@ -106,4 +267,251 @@ rfft_impls! {
     (RFftN1024, CFftN512),
     (RFftN2048, CFftN1024),
     (RFftN4096, CFftN2048),
+    (RFftN8192, CFftN4096),
+    (RFftN16384, CFftN8192),
+    (RFftN32768, CFftN16384),
+    (RFftN65536, CFftN32768),
+}
+
+/// Array of `f32` values of a supported RFFT size.
+///
+/// This trait is implemented by `[f32; N]` for all sizes `N` supported by the
+/// selected `maxn-*` feature. It allows writing code that is generic over the
+/// size of the array. The trait is sealed and cannot be implemented outside
+/// of microfft.
+///
+/// # Example
+///
+/// ```
+/// use microfft::real::RFftArray;
+///
+/// fn nyquist<const N: usize>(input: &mut [f32; N]) -> f32
+/// where
+///     [f32; N]: RFftArray,
+/// {
+///     input.transform_packed();
+///     input[1]
+/// }
+///
+/// let mut input = [1., -1., 1., -1., 1., -1., 1., -1.];
+/// assert_eq!(nyquist(&mut input), 8.);
+/// ```
+pub trait RFftArray: Sealed {
+    /// The output buffer type, an array of `N/2` `Complex32` values.
+    type Output;
+
+    /// Perform an in-place RFFT.
+    ///
+    /// See the [`real`] module for the layout of the output.
+    ///
+    /// [`real`]: ../real/index.html
+    fn transform(&mut self) -> &mut Self::Output;
+
+    /// Perform an in-place RFFT, storing the Nyquist term in the imaginary
+    /// part of the DC value.
+    fn transform_packed(&mut self) -> &mut Self::Output;
+
+    /// Perform an in-place inverse RFFT on the output of the packed RFFT.
+    ///
+    /// The output is scaled by `1/N`, restoring the original input values.
+    fn inverse_transform(spectrum: &mut Self::Output) -> &mut Self;
+}
+
+/// Reinterpret a slice of `M` values as an array.
+///
+/// The transforms return slices of the length given by their size marker
+/// type, so this cast cannot fail.
+#[inline]
+fn as_array<T, const M: usize>(x: &mut [T]) -> &mut [T; M] {
+    debug_assert_eq!(x.len(), M);
+    unsafe { &mut *x.as_mut_ptr().cast::<[T; M]>() }
+}
+
+macro_rules! rfft_public_impls {
+    ( $( $(#[$meta:meta])* $N:literal => ($M:literal, $RFftN:ident), )* ) => {
+        $(
+            $(#[$meta])*
+            impl Sealed for [f32; $N] {}
+
+            $(#[$meta])*
+            impl RFftArray for [f32; $N] {
+                type Output = [Complex32; $M];
+
+                #[inline]
+                fn transform(&mut self) -> &mut Self::Output {
+                    as_array(<$RFftN as RFft>::transform(self))
+                }
+
+                #[inline]
+                fn transform_packed(&mut self) -> &mut Self::Output {
+                    as_array(<$RFftN as RFft>::transform_packed(self))
+                }
+
+                #[inline]
+                fn inverse_transform(spectrum: &mut Self::Output) -> &mut Self {
+                    as_array(<$RFftN as RFft>::inverse_transform(spectrum))
+                }
+            }
+
+            $(#[$meta])*
+            impl Sealed for $RFftN {}
+
+            $(#[$meta])*
+            impl Fft for $RFftN {
+                type Input = [f32; $N];
+                type Output = [Complex32; $M];
+
+                const N: usize = <Self as RFft>::N;
+                const LOG2_N: usize = <<Self as RFft>::CFft as CFft>::LOG2_N + 1;
+
+                #[inline]
+                fn transform(input: &mut Self::Input) -> &mut Self::Output {
+                    as_array(<Self as RFft>::transform(input))
+                }
+            }
+        )*
+    };
+}
+
+rfft_public_impls! {
+    2 => (1, RFftN2),
+    4 => (2, RFftN4),
+    #[cfg(any(
+        feature = "maxn-8",
+        feature = "maxn-16",
+        feature = "maxn-32",
+        feature = "maxn-64",
+        feature = "maxn-128",
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    8 => (4, RFftN8),
+    #[cfg(any(
+        feature = "maxn-16",
+        feature = "maxn-32",
+        feature = "maxn-64",
+        feature = "maxn-128",
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    16 => (8, RFftN16),
+    #[cfg(any(
+        feature = "maxn-32",
+        feature = "maxn-64",
+        feature = "maxn-128",
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    32 => (16, RFftN32),
+    #[cfg(any(
+        feature = "maxn-64",
+        feature = "maxn-128",
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    64 => (32, RFftN64),
+    #[cfg(any(
+        feature = "maxn-128",
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    128 => (64, RFftN128),
+    #[cfg(any(
+        feature = "maxn-256",
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    256 => (128, RFftN256),
+    #[cfg(any(
+        feature = "maxn-512",
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    512 => (256, RFftN512),
+    #[cfg(any(
+        feature = "maxn-1024",
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    1024 => (512, RFftN1024),
+    #[cfg(any(
+        feature = "maxn-2048",
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    2048 => (1024, RFftN2048),
+    #[cfg(any(
+        feature = "maxn-4096",
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    4096 => (2048, RFftN4096),
+    #[cfg(any(
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    8192 => (4096, RFftN8192),
+    #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+    16384 => (8192, RFftN16384),
+    #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+    32768 => (16384, RFftN32768),
+    #[cfg(feature = "maxn-65536")]
+    65536 => (32768, RFftN65536),
 }
@@ -0,0 +1,152 @@
+//! Sliding DFT for per-sample spectrum updates
+//!
+//! The sliding DFT keeps the spectrum of the last `N` samples of a signal up
+//! to date, updating each bin in constant time whenever a new sample arrives.
+//! This is much cheaper than recomputing an `N`-point FFT for every sample.
+//!
+//! Rounding errors accumulate with every update. To bound this numerical
+//! drift, the spectrum is periodically recomputed from scratch using a full
+//! CFFT.
+
+use crate::cfft::{twiddle, CFftArray};
+use core::ops::Range;
+use num_complex::Complex32;
+
+/// Sliding `N`-point DFT over a stream of real samples.
+///
+/// The spectrum always refers to the last `N` pushed samples, with the oldest
+/// sample at index 0 of the DFT input. Initially, all samples are zero.
+///
+/// # Example
+///
+/// ```
+/// use microfft::sliding::SlidingDft;
+/// use std::f32::consts::PI;
+///
+/// let mut sdft = SlidingDft::<16>::new();
+/// for i in 0..100 {
+///     // a sine wave at bin 3
+///     sdft.push((2. * PI * 3. * i as f32 / 16.).sin());
+///
+///     if i >= 15 {
+///         let amplitude = sdft.bin(3).norm();
+///         assert!((amplitude - 8.).abs() < 1e-3);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SlidingDft<const N: usize> {
+    samples: [f32; N],
+    pos: usize,
+    bins: [Complex32; N],
+    twiddles: [Complex32; N],
+    range: Range<usize>,
+    resync_interval: usize,
+    since_resync: usize,
+}
+
+impl<const N: usize> SlidingDft<N>
+where
+    [Complex32; N]: CFftArray,
+{
+    /// Create a sliding DFT updating all bins.
+    ///
+    /// The spectrum is resynchronized every `N` samples.
+    pub fn new() -> Self {
+        Self::with_bins(0..N)
+    }
+
+    /// Create a sliding DFT updating only the given range of bins.
+    ///
+    /// The values of the other bins are unspecified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bins` is not within `0..N`.
+    pub fn with_bins(bins: Range<usize>) -> Self {
+        assert!(bins.start <= bins.end && bins.end <= N, "bins out of range");
+
+        let mut twiddles = [Complex32::default(); N];
+        for (k, w) in twiddles.iter_mut().enumerate() {
+            *w = twiddle(k, N, true);
+        }
+
+        Self {
+            samples: [0.; N],
+            pos: 0,
+            bins: [Complex32::default(); N],
+            twiddles,
+            range: bins,
+            resync_interval: N,
+            since_resync: 0,
+        }
+    }
+
+    /// Set the number of samples after which the spectrum is recomputed.
+    ///
+    /// Setting the interval to zero disables periodic resynchronization.
+    /// The samples pushed before changing the interval are not counted
+    /// towards the new one.
+    pub fn set_resync_interval(&mut self, interval: usize) {
+        self.resync_interval = interval;
+        self.since_resync = 0;
+    }
+
+    /// Push the next sample, dropping the oldest one from the window.
+    pub fn push(&mut self, sample: f32) {
+        let delta = sample - self.samples[self.pos];
+        self.samples[self.pos] = sample;
+        self.pos = (self.pos + 1) % N;
+
+        self.since_resync += 1;
+        if self.resync_interval != 0 && self.since_resync >= self.resync_interval {
+            self.resync();
+            return;
+        }
+
+        let range = self.range.clone();
+        for (x, w) in self.bins[range.clone()]
+            .iter_mut()
+            .zip(&self.twiddles[range])
+        {
+            *x = (*x + delta) * w;
+        }
+    }
+
+    /// Recompute the spectrum from the samples in the window.
+    pub fn resync(&mut self) {
+        let mut buffer = [Complex32::default(); N];
+        for (i, x) in buffer.iter_mut().enumerate() {
+            x.re = self.samples[(self.pos + i) % N];
+        }
+        buffer.transform();
+
+        let range = self.range.clone();
+        self.bins[range.clone()].copy_from_slice(&buffer[range]);
+        self.since_resync = 0;
+    }
+
+    /// Return the value of bin `k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not within the range of updated bins.
+    pub fn bin(&self, k: usize) -> Complex32 {
+        assert!(self.range.contains(&k), "bin is not updated");
+        self.bins[k]
+    }
+
+    /// Return the values of the updated bins.
+    pub fn bins(&self) -> &[Complex32] {
+        &self.bins[self.range.clone()]
+    }
+}
+
+impl<const N: usize> Default for SlidingDft<N>
+where
+    [Complex32; N]: CFftArray,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
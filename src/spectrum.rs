@@ -0,0 +1,126 @@
+//! Conversion of RFFT output to magnitude, power and decibel spectra
+//!
+//! The functions in this module convert the output of an `N`-point RFFT
+//! in-place into a one-sided spectrum of `N/2 + 1` real values, reusing the
+//! buffer of the complex bins. The last value holds the Nyquist term, which
+//! is taken from the imaginary part of the DC value as stored by the packed
+//! RFFT (`real::rfft_packed_*`). For the output of the plain RFFT, it is
+//! always zero.
+//!
+//! The values are scaled so that they describe the components of the input
+//! signal independently of `N`:
+//!
+//! - [`magnitude`] yields the amplitude of each component, e.g. `A` for a
+//!   sinusoid `A * cos(2π * k * n / N)`.
+//! - [`power`] yields the mean power of each component, e.g. `A²/2` for a
+//!   sinusoid. The sum of all values is the mean power of the input.
+//! - [`db`] yields the amplitude in decibels, `20 * log10(A)`, so a
+//!   sinusoid with an amplitude of `1` has a level of `0` dB.
+//!
+//! Since the negative-frequency terms are folded into the positive-frequency
+//! ones, all bins except for DC and Nyquist are doubled. If a window was
+//! applied to the input, the values should be corrected by its coherent
+//! gain (for amplitudes) or energy gain (for power).
+//!
+//! Square roots and logarithms are computed using fast approximations that
+//! do not depend on libm, which are accurate to about the precision of
+//! `f32`. Amplitudes below `1e-19` are affected by underflow, since the
+//! computations are based on squared amplitudes.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{real::rfft_16, spectrum};
+//! use std::f32::consts::PI;
+//!
+//! // a sine wave with amplitude 2 at bin 3
+//! let mut samples: Vec<_> = (0..16)
+//!     .map(|i| 2. * (2. * PI * 3. * i as f32 / 16.).sin())
+//!     .collect();
+//!
+//! let bins = rfft_16(&mut samples);
+//! let amplitudes = spectrum::magnitude(bins);
+//!
+//! assert_eq!(amplitudes.len(), 9);
+//! assert!((amplitudes[3] - 2.).abs() < 1e-5);
+//! ```
+//!
+//! [`magnitude`]: fn.magnitude.html
+//! [`power`]: fn.power.html
+//! [`db`]: fn.db.html
+
+use crate::{math, rfft::as_real};
+use num_complex::Complex32;
+
+/// Convert RFFT output in-place into an amplitude spectrum.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{spectrum, Complex32};
+///
+/// let mut bins = [Complex32::new(8., 0.), Complex32::new(3., 4.)];
+/// let amplitudes = spectrum::magnitude(&mut bins);
+/// assert_eq!(amplitudes, &[2., 2.5, 0.]);
+/// ```
+pub fn magnitude(spectrum: &mut [Complex32]) -> &mut [f32] {
+    convert(spectrum, |a2, _| math::sqrt(a2))
+}
+
+/// Convert RFFT output in-place into a power spectrum.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{spectrum, Complex32};
+///
+/// let mut bins = [Complex32::new(8., 0.), Complex32::new(3., 4.)];
+/// let power = spectrum::power(&mut bins);
+/// assert_eq!(power, &[4., 3.125, 0.]);
+/// ```
+pub fn power(spectrum: &mut [Complex32]) -> &mut [f32] {
+    convert(spectrum, |a2, edge| if edge { a2 } else { a2 / 2. })
+}
+
+/// Convert RFFT output in-place into an amplitude spectrum in decibels.
+///
+/// Bins with an amplitude of zero are converted to negative infinity.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{spectrum, Complex32};
+///
+/// let mut bins = [Complex32::new(40., 0.), Complex32::new(0., 2.)];
+/// let levels = spectrum::db(&mut bins);
+/// assert!((levels[0] - 20.).abs() < 1e-5);
+/// assert_eq!(levels[2], f32::NEG_INFINITY);
+/// ```
+pub fn db(spectrum: &mut [Complex32]) -> &mut [f32] {
+    convert(spectrum, |a2, _| 10. * math::log10(a2))
+}
+
+/// Apply `f` to the squared amplitude of each bin, along with whether the
+/// bin is the DC or Nyquist term, and store the results in natural order.
+fn convert(spectrum: &mut [Complex32], f: impl Fn(f32, bool) -> f32) -> &mut [f32] {
+    if spectrum.is_empty() {
+        return &mut [];
+    }
+
+    let m = spectrum.len();
+    let scale = 1. / (m * 2) as f32;
+    let x = as_real(spectrum);
+
+    let (dc, nyquist) = (x[0] * scale, x[1] * scale);
+    x[0] = f(dc * dc, true);
+
+    // the values are written in ascending order, only overwriting bins that
+    // have already been read
+    for k in 1..m {
+        let (re, im) = (x[2 * k] * 2. * scale, x[2 * k + 1] * 2. * scale);
+        x[k] = f(re * re + im * im, false);
+    }
+
+    x[m] = f(nyquist * nyquist, true);
+    &mut x[..=m]
+}
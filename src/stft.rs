@@ -0,0 +1,277 @@
+//! Short-time Fourier transform (STFT)
+//!
+//! The STFT splits a continuous stream of samples into overlapping frames of
+//! `N` samples, which start every `hop` samples. Each frame is multiplied by
+//! a window and transformed using the RFFT, yielding a sequence of spectra
+//! (a spectrogram).
+//!
+//! [`Stft`] buffers the incoming samples in a fixed-size ring buffer, so
+//! samples can be pushed one at a time or in blocks of any size, e.g. as they
+//! are delivered by an ADC. No heap allocations are performed.
+//!
+//! The frames are computed using the packed RFFT, i.e. the imaginary part of
+//! the DC value holds the Nyquist term (see the [`real`] module).
+//!
+//! [`Istft`] reverses this process: It transforms each (possibly modified)
+//! frame back using the inverse RFFT, multiplies it by the window again and
+//! adds it to the overlapping parts of the previous frames. The result is
+//! divided by the sum of the squared window values that contributed to each
+//! sample (weighted overlap-add). Unmodified frames are reconstructed
+//! exactly wherever this sum is at least `1e-3` times its largest value.
+//! Samples to which only window values close to zero contributed are output
+//! as zero.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{
+//!     stft::{Istft, Stft},
+//!     window::Window,
+//! };
+//!
+//! let mut stft = Stft::<64>::new(Window::Hann, 16);
+//! let mut istft = Istft::<64>::new(Window::Hann, 16);
+//!
+//! let signal: Vec<_> = (0..1024).map(|i| (i as f32 * 0.1).sin()).collect();
+//! let mut output = Vec::new();
+//! stft.process(&signal, |frame| {
+//!     // remove the DC component
+//!     frame[0].re = 0.;
+//!     output.extend_from_slice(istft.push(frame));
+//! });
+//!
+//! // the output starts with the first sample of the signal
+//! assert_eq!(output.len(), 1024 - 64 + 16);
+//! ```
+//!
+//! [`Istft`]: struct.Istft.html
+//! [`Stft`]: struct.Stft.html
+//! [`real`]: ../real/index.html
+
+use crate::{rfft::RFftArray, window::Window};
+
+/// The smallest sum of squared window values relative to the largest one for
+/// which a sample is reconstructed.
+const MIN_RELATIVE_WEIGHT: f32 = 1e-3;
+
+/// Streaming `N`-point STFT of real samples.
+///
+/// A frame is computed from the last `N` samples as soon as `N` samples have
+/// been pushed, and then every `hop` samples.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{stft::Stft, window::Window};
+/// use std::f32::consts::PI;
+///
+/// let mut stft = Stft::<64>::new(Window::Hann, 16);
+///
+/// // a sine wave at bin 8
+/// let samples: Vec<_> = (0..256)
+///     .map(|i| (2. * PI * 8. * i as f32 / 64.).sin())
+///     .collect();
+///
+/// let mut frames = 0;
+/// for block in samples.chunks(40) {
+///     stft.process(block, |frame| {
+///         let peak = frame[8].norm();
+///         assert!(frame.iter().all(|c| c.norm() <= peak));
+///         frames += 1;
+///     });
+/// }
+/// assert_eq!(frames, 13);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Stft<const N: usize> {
+    window: [f32; N],
+    samples: [f32; N],
+    frame: [f32; N],
+    pos: usize,
+    hop: usize,
+    remaining: usize,
+}
+
+impl<const N: usize> Stft<N>
+where
+    [f32; N]: RFftArray,
+{
+    /// Create an STFT using the given window, computing a frame every `hop`
+    /// samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hop` is zero or greater than `N`.
+    pub fn new(window: Window, hop: usize) -> Self {
+        assert!(hop > 0 && hop <= N, "hop size out of range");
+
+        let mut values = [0.; N];
+        for (i, w) in values.iter_mut().enumerate() {
+            *w = window.value(i, N);
+        }
+
+        Self {
+            window: values,
+            samples: [0.; N],
+            frame: [0.; N],
+            pos: 0,
+            hop,
+            remaining: N,
+        }
+    }
+
+    /// Push the next sample, returning a frame if a hop completed.
+    ///
+    /// The frame is overwritten by the next call that returns a frame.
+    pub fn push(&mut self, sample: f32) -> Option<&mut <[f32; N] as RFftArray>::Output> {
+        self.samples[self.pos] = sample;
+        self.pos = (self.pos + 1) % N;
+
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            return None;
+        }
+        self.remaining = self.hop;
+
+        // `pos` is the index of the oldest sample
+        let (newer, older) = self.samples.split_at(self.pos);
+        let ordered = older.iter().chain(newer);
+        for ((f, x), w) in self.frame.iter_mut().zip(ordered).zip(&self.window) {
+            *f = x * w;
+        }
+        Some(self.frame.transform_packed())
+    }
+
+    /// Push a block of samples, calling `f` with each completed frame.
+    pub fn process<F>(&mut self, samples: &[f32], mut f: F)
+    where
+        F: FnMut(&mut <[f32; N] as RFftArray>::Output),
+    {
+        for &sample in samples {
+            if let Some(frame) = self.push(sample) {
+                f(frame);
+            }
+        }
+    }
+
+    /// Return the number of samples between the starts of two frames.
+    pub fn hop(&self) -> usize {
+        self.hop
+    }
+
+    /// Discard all buffered samples.
+    ///
+    /// The next frame is computed once `N` new samples have been pushed.
+    pub fn reset(&mut self) {
+        self.samples = [0.; N];
+        self.pos = 0;
+        self.remaining = N;
+    }
+}
+
+/// Inverse `N`-point STFT using weighted overlap-add.
+///
+/// Frames are expected in the format produced by [`Stft`], using the same
+/// window and hop size. Each pushed frame completes `hop` output samples.
+/// The first frame yields the first `hop` samples of the signal that was
+/// passed to the STFT, so the total latency of the STFT and the inverse STFT
+/// is `N` samples.
+///
+/// Samples whose sum of squared window values is less than `1e-3` times the
+/// largest such sum, like the first sample of a signal analyzed using the Hann
+/// window, cannot be reconstructed reliably. They are set to zero instead of
+/// amplifying rounding errors.
+///
+/// [`Stft`]: struct.Stft.html
+#[derive(Clone, Debug)]
+pub struct Istft<const N: usize> {
+    window: [f32; N],
+    samples: [f32; N],
+    weights: [f32; N],
+    output: [f32; N],
+    min_weight: f32,
+    pos: usize,
+    hop: usize,
+}
+
+impl<const N: usize> Istft<N>
+where
+    [f32; N]: RFftArray,
+{
+    /// Create an inverse STFT using the given window, expecting a frame every
+    /// `hop` samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hop` is zero or greater than `N`.
+    pub fn new(window: Window, hop: usize) -> Self {
+        assert!(hop > 0 && hop <= N, "hop size out of range");
+
+        let mut values = [0.; N];
+        for (i, w) in values.iter_mut().enumerate() {
+            *w = window.value(i, N);
+        }
+
+        // once the first `N` samples have passed, the weights repeat with a
+        // period of `hop` samples
+        let mut max_weight = 0.;
+        for start in 0..hop {
+            let weight: f32 = values[start..].iter().step_by(hop).map(|w| w * w).sum();
+            if weight > max_weight {
+                max_weight = weight;
+            }
+        }
+
+        Self {
+            window: values,
+            samples: [0.; N],
+            weights: [0.; N],
+            output: [0.; N],
+            min_weight: max_weight * MIN_RELATIVE_WEIGHT,
+            pos: 0,
+            hop,
+        }
+    }
+
+    /// Add the next frame, returning the `hop` completed output samples.
+    ///
+    /// The frame is used as a buffer for the inverse RFFT and overwritten.
+    pub fn push(&mut self, frame: &mut <[f32; N] as RFftArray>::Output) -> &mut [f32] {
+        let x = <[f32; N] as RFftArray>::inverse_transform(frame);
+
+        // `pos` is the index of the first sample of the frame
+        for (i, (x, w)) in x.iter().zip(&self.window).enumerate() {
+            let j = (self.pos + i) % N;
+            self.samples[j] += x * w;
+            self.weights[j] += w * w;
+        }
+
+        // no later frame overlaps with the first `hop` samples of this one
+        for (i, y) in self.output[..self.hop].iter_mut().enumerate() {
+            let j = (self.pos + i) % N;
+            let weight = self.weights[j];
+            *y = if weight >= self.min_weight && weight > 0. {
+                self.samples[j] / weight
+            } else {
+                0.
+            };
+            self.samples[j] = 0.;
+            self.weights[j] = 0.;
+        }
+
+        self.pos = (self.pos + self.hop) % N;
+        &mut self.output[..self.hop]
+    }
+
+    /// Return the number of samples between the starts of two frames.
+    pub fn hop(&self) -> usize {
+        self.hop
+    }
+
+    /// Discard the overlapping parts of all previous frames.
+    pub fn reset(&mut self) {
+        self.samples = [0.; N];
+        self.weights = [0.; N];
+        self.pos = 0;
+    }
+}
@@ -0,0 +1,232 @@
+//! Window functions
+//!
+//! Applying a window to a block of samples before computing its spectrum
+//! reduces the leakage caused by the discontinuities at the block
+//! boundaries. This module provides a set of common windows, which can be
+//! applied to the input of the RFFT in-place.
+//!
+//! The windows are periodic (DFT-even), i.e. an `N`-point window equals the
+//! first `N` values of the symmetric window of size `N + 1`. This is the
+//! appropriate form for spectral analysis.
+//!
+//! Windowing changes the magnitude of the spectrum. The [coherent gain] is
+//! the factor by which the amplitudes of sinusoids are scaled, the [energy
+//! gain] is the factor by which the power of noise is scaled. Dividing the
+//! spectrum by the coherent gain (or the power spectrum by the energy gain)
+//! corrects for the window.
+//!
+//! The cosine terms of the windows are looked up in microfft's sine table if
+//! the window size is a power of two supported by the selected `maxn-*`
+//! feature. Otherwise, they are computed at runtime.
+//!
+//! # Example
+//!
+//! ```
+//! use microfft::{real::rfft_64, window::Window};
+//! use std::f32::consts::PI;
+//!
+//! // a sine wave between bins 5 and 6
+//! let mut samples: Vec<_> = (0..64)
+//!     .map(|i| (2. * PI * 5.5 * i as f32 / 64.).sin())
+//!     .collect();
+//!
+//! Window::Hann.apply(&mut samples);
+//! let spectrum = rfft_64(&mut samples);
+//!
+//! // far from the signal, the leakage is small
+//! let gain = Window::Hann.coherent_gain(64);
+//! assert!(spectrum[20].norm() / gain < 0.01);
+//! ```
+//!
+//! [coherent gain]: enum.Window.html#method.coherent_gain
+//! [energy gain]: enum.Window.html#method.energy_gain
+
+use crate::{
+    cfft::{twiddle, MAX_N},
+    math,
+};
+
+/// A window function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Window {
+    /// The Hann window.
+    Hann,
+    /// The Hamming window.
+    Hamming,
+    /// The Blackman window.
+    Blackman,
+    /// The 4-term Blackman-Harris window.
+    BlackmanHarris,
+    /// The flat-top window, for measuring the amplitudes of sinusoids.
+    FlatTop,
+    /// The Kaiser window.
+    Kaiser {
+        /// The shape parameter, trading main lobe width for side lobe level.
+        ///
+        /// It must be in the range `[0, 80]`, since the window values cannot
+        /// be computed in `f32` for larger values.
+        beta: f32,
+    },
+}
+
+impl Window {
+    /// Compute the window value at index `i` of an `n`-point window.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use microfft::window::Window;
+    ///
+    /// assert_eq!(Window::Hann.value(0, 8), 0.);
+    /// assert_eq!(Window::Hann.value(4, 8), 1.);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not less than `n`, or if the `beta` of a Kaiser
+    /// window is out of range.
+    pub fn value(self, i: usize, n: usize) -> f32 {
+        assert!(i < n, "window index out of range");
+        self.value_with(i, n, self.scale())
+    }
+
+    /// Multiply `samples` by the window in-place.
+    ///
+    /// The window size is the length of `samples`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use microfft::window::Window;
+    ///
+    /// let mut samples = [1.; 16];
+    /// Window::Blackman.apply(&mut samples);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `beta` of a Kaiser window is out of range.
+    pub fn apply(self, samples: &mut [f32]) {
+        let n = samples.len();
+        for (x, w) in samples.iter_mut().zip(self.values(n)) {
+            *x *= w;
+        }
+    }
+
+    /// Compute the coherent gain of an `n`-point window.
+    ///
+    /// This is the mean of the window values, by which the amplitude of a
+    /// sinusoid in the spectrum is scaled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use microfft::window::Window;
+    ///
+    /// let gain = Window::Hann.coherent_gain(64);
+    /// assert!((gain - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn coherent_gain(self, n: usize) -> f32 {
+        let sum: f32 = self.values(n).sum();
+        sum / n as f32
+    }
+
+    /// Compute the energy gain of an `n`-point window.
+    ///
+    /// This is the mean of the squared window values, by which the power of
+    /// broadband noise in the spectrum is scaled.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use microfft::window::Window;
+    ///
+    /// let gain = Window::Hann.energy_gain(64);
+    /// assert!((gain - 0.375).abs() < 1e-6);
+    /// ```
+    pub fn energy_gain(self, n: usize) -> f32 {
+        let sum: f32 = self.values(n).map(|w| w * w).sum();
+        sum / n as f32
+    }
+
+    /// Iterate over the values of an `n`-point window.
+    fn values(self, n: usize) -> impl Iterator<Item = f32> {
+        let scale = self.scale();
+        (0..n).map(move |i| self.value_with(i, n, scale))
+    }
+
+    /// Compute the normalization shared by all values of the window.
+    ///
+    /// This is `I0(beta)` for the Kaiser window and unused otherwise.
+    fn scale(self) -> f32 {
+        match self {
+            Self::Kaiser { beta } => {
+                assert!((0. ..=80.).contains(&beta), "Kaiser beta out of range");
+                bessel_i0(beta * beta)
+            }
+            _ => 1.,
+        }
+    }
+
+    /// Compute the window value at index `i`, given the result of `scale`.
+    fn value_with(self, i: usize, n: usize, scale: f32) -> f32 {
+        match self {
+            Self::Hann => cosine_sum(&[0.5, 0.5], i, n),
+            Self::Hamming => cosine_sum(&[0.54, 0.46], i, n),
+            Self::Blackman => cosine_sum(&[0.42, 0.5, 0.08], i, n),
+            Self::BlackmanHarris => cosine_sum(&[0.35875, 0.48829, 0.14128, 0.01168], i, n),
+            Self::FlatTop => cosine_sum(
+                &[
+                    0.215_578_95,
+                    0.416_631_58,
+                    0.277_263_16,
+                    0.083_578_95,
+                    0.006_947_368,
+                ],
+                i,
+                n,
+            ),
+            Self::Kaiser { beta } => {
+                let t = (2 * i) as f32 / n as f32 - 1.;
+                bessel_i0(beta * beta * (1. - t * t)) / scale
+            }
+        }
+    }
+}
+
+/// Evaluate the cosine-sum window `a[0] - a[1] * cos(2πi/n) + a[2] * cos(4πi/n) - ...`.
+fn cosine_sum(a: &[f32], i: usize, n: usize) -> f32 {
+    let mut sign = 1.;
+    let mut sum = 0.;
+    for (m, a) in a.iter().enumerate() {
+        sum += sign * a * cos(m * i, n);
+        sign = -sign;
+    }
+    sum
+}
+
+/// Compute `cos(2π * k / n)`.
+fn cos(k: usize, n: usize) -> f32 {
+    let k = k % n;
+    if n.is_power_of_two() && n <= MAX_N {
+        twiddle::<f32>(k, n, false).re
+    } else {
+        math::cos_sin(k, n).0
+    }
+}
+
+/// Compute the modified Bessel function of the first kind `I0(x)`, given
+/// `x²`.
+fn bessel_i0(x2: f32) -> f32 {
+    let q = x2 / 4.;
+    let mut term = 1.;
+    let mut sum = 1.;
+    let mut k = 1.;
+    while term > sum * 1e-8 {
+        term *= q / (k * k);
+        sum += term;
+        k += 1.;
+    }
+    sum
+}
@@ -0,0 +1,142 @@
+//! Zoom FFT (chirp-z transform)
+//!
+//! The zoom FFT evaluates the spectrum of an input at a number of equally
+//! spaced frequencies in a narrow band, with a resolution that is independent
+//! of the input size. This is much cheaper than computing a large FFT and
+//! then discarding all but a few of its output values.
+//!
+//! Frequencies are given as fractions of the sample rate, in cycles per
+//! sample. For example, to inspect the band from 1000 Hz to 1200 Hz of a
+//! signal sampled at 48 kHz with a resolution of 1 Hz, use a start frequency
+//! of `1000. / 48000.` and a step of `1. / 48000.`, with 200 output values.
+//!
+//! The transform is computed using Bluestein's algorithm, which requires
+//! power-of-two FFTs of at least the sum of the input and output sizes. They
+//! operate on a scratch buffer provided by the caller, whose required size is
+//! returned by [`scratch_len`].
+//!
+//! [`scratch_len`]: fn.scratch_len.html
+
+use crate::{bluestein, cfft::MAX_N, math, FftError};
+use num_complex::Complex32;
+
+/// Compute the spectrum of `input` at the frequencies `start + k * step`, for
+/// each output index `k`.
+///
+/// The input is left unchanged. The output is written to `output`, with
+/// `output[k]` holding the DFT of the input evaluated at frequency
+/// `start + k * step`, in cycles per sample.
+///
+/// # Example
+///
+/// ```
+/// use microfft::{zoom, Complex32};
+/// use std::f32::consts::PI;
+///
+/// const N: usize = 1024;
+/// const K: usize = 64;
+///
+/// // a sine wave at 0.1 cycles per sample
+/// let mut input = [Complex32::default(); N];
+/// for (i, x) in input.iter_mut().enumerate() {
+///     x.re = (2. * PI * 0.1 * i as f32).sin();
+/// }
+///
+/// // zoom into the band from 0.09 to 0.11 cycles per sample
+/// let mut output = [Complex32::default(); K];
+/// let mut scratch = [Complex32::default(); zoom::scratch_len(N, K)];
+/// let spectrum = zoom::zoom_fft(&input, 0.09, 0.02 / K as f32, &mut output, &mut scratch);
+///
+/// // the spectrum has a peak at the frequency of the sine wave
+/// let peak = spectrum
+///     .unwrap()
+///     .iter()
+///     .enumerate()
+///     .max_by(|(_, a), (_, b)| a.norm().partial_cmp(&b.norm()).unwrap())
+///     .map(|(k, _)| k);
+/// assert_eq!(peak, Some(32));
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `input` or `output` is empty, if the required FFT size
+/// exceeds the size selected by the `maxn-*` features, or if `scratch` is too
+/// small.
+pub fn zoom_fft<'a>(
+    input: &[Complex32],
+    start: f32,
+    step: f32,
+    output: &'a mut [Complex32],
+    scratch: &mut [Complex32],
+) -> Result<&'a mut [Complex32], FftError> {
+    let (n, k) = (input.len(), output.len());
+    if n == 0 || k == 0 {
+        return Err(FftError::TooSmall);
+    }
+
+    let m = fft_len(n, k);
+    if m > MAX_N {
+        return Err(FftError::TooLarge);
+    } else if scratch.len() < 2 * m {
+        return Err(FftError::ScratchTooSmall);
+    }
+
+    let (a, b) = scratch[..(2 * m)].split_at_mut(m);
+    a.iter_mut().for_each(|c| *c = Complex32::default());
+    b.iter_mut().for_each(|c| *c = Complex32::default());
+
+    let (start, step) = (f64::from(start), f64::from(step));
+
+    // a[j] = x[j] * e^(-2πi * (start * j + step * j² / 2))
+    for (j, (a_j, x_j)) in a.iter_mut().zip(input).enumerate() {
+        *a_j = x_j * cis(-(start * j as f64 + chirp_turns(j, step)));
+    }
+
+    // b[j] = e^(πi * step * j²), for `j` in `(-n, k)`
+    for (j, b_j) in b[..k].iter_mut().enumerate() {
+        *b_j = cis(chirp_turns(j, step));
+    }
+    for j in 1..n {
+        b[m - j] = cis(chirp_turns(j, step));
+    }
+
+    bluestein::convolve(a, b);
+
+    for (j, (y_j, a_j)) in output.iter_mut().zip(a.iter()).enumerate() {
+        *y_j = a_j * cis(-chirp_turns(j, step));
+    }
+    Ok(output)
+}
+
+/// Return the minimum scratch buffer length required by [`zoom_fft`] to
+/// transform an input of size `input_len` into `output_len` output values.
+///
+/// [`zoom_fft`]: fn.zoom_fft.html
+pub const fn scratch_len(input_len: usize, output_len: usize) -> usize {
+    2 * fft_len(input_len, output_len)
+}
+
+/// Return the size of the power-of-two FFTs required for the given input and
+/// output sizes.
+const fn fft_len(n: usize, k: usize) -> usize {
+    if n == 0 || k == 0 {
+        0
+    } else if n + k - 1 < 2 {
+        // the smallest supported FFT size
+        2
+    } else {
+        (n + k - 1).next_power_of_two()
+    }
+}
+
+/// Compute the phase `step * j² / 2` of the chirp at index `j`, in turns.
+fn chirp_turns(j: usize, step: f64) -> f64 {
+    let j = j as f64;
+    step * j * j / 2.
+}
+
+/// Compute `e^(2πi * t)`.
+fn cis(t: f64) -> Complex32 {
+    let (cos, sin) = math::cos_sin_turns(t);
+    Complex32::new(cos, sin)
+}
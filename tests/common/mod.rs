@@ -0,0 +1,21 @@
+// Each test crate only uses some of these helpers.
+#![allow(dead_code)]
+
+/// Generate `n` bounded, non-periodic test values in `[-5, 5]`.
+pub fn test_signal(n: usize) -> Vec<f32> {
+    (0..n).map(|i| ((i * 7) % 11) as f32 - 5.).collect()
+}
+
+/// Return the largest absolute value, or `1` if all values are smaller.
+pub fn max_abs(values: &[f32]) -> f32 {
+    values.iter().fold(1_f32, |max, x| max.max(x.abs()))
+}
+
+// The tolerance is relative to the given magnitude, since values close to zero
+// cannot be computed with a small relative error.
+pub fn assert_values_eq(result: &[f32], expected: &[f32], magnitude: f32, tolerance: f32) {
+    assert_eq!(result.len(), expected.len());
+    for (r, e) in result.iter().zip(expected) {
+        assert!((r - e).abs() <= magnitude * tolerance, "{} != {}", r, e);
+    }
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use common::{assert_values_eq, max_abs, test_signal};
+use std::f64::consts::PI;
+
+fn naive_dct2(input: &[f32], k: usize) -> f32 {
+    let n = input.len() as f64;
+    let sum: f64 = input
+        .iter()
+        .enumerate()
+        .map(|(i, x)| f64::from(*x) * (PI * (2 * i + 1) as f64 * k as f64 / (2. * n)).cos())
+        .sum();
+    sum as f32
+}
+
+macro_rules! dct_tests {
+    ( $( $(#[$meta:meta])* $name:ident: ($N:expr, $dct2:ident, $dct3:ident), )* ) => {
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() {
+                let input = test_signal($N);
+
+                // compare a bounded number of bins against the O(N^2) DCT
+                let step = ($N / 256).max(1);
+                let mut result = input.clone();
+                let result = microfft::dct::$dct2(&mut result);
+                let bins: Vec<_> = (0..$N).step_by(step).collect();
+                let expected: Vec<_> = bins.iter().map(|&k| naive_dct2(&input, k)).collect();
+                let sampled: Vec<_> = bins.iter().map(|&k| result[k]).collect();
+                assert_values_eq(&sampled, &expected, max_abs(&expected), 1e-5);
+
+                let restored = microfft::dct::$dct3(result);
+                assert_values_eq(restored, &input, max_abs(&input), 1e-5);
+            }
+        )*
+    };
+}
+
+dct_tests! {
+    dct_2: (2, dct2_2, dct3_2),
+    dct_4: (4, dct2_4, dct3_4),
+    dct_8: (8, dct2_8, dct3_8),
+    dct_16: (16, dct2_16, dct3_16),
+    dct_32: (32, dct2_32, dct3_32),
+    dct_64: (64, dct2_64, dct3_64),
+    dct_128: (128, dct2_128, dct3_128),
+    dct_256: (256, dct2_256, dct3_256),
+    dct_512: (512, dct2_512, dct3_512),
+    dct_1024: (1024, dct2_1024, dct3_1024),
+    dct_2048: (2048, dct2_2048, dct3_2048),
+    dct_4096: (4096, dct2_4096, dct3_4096),
+    #[cfg(any(
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    dct_8192: (8192, dct2_8192, dct3_8192),
+    #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+    dct_16384: (16384, dct2_16384, dct3_16384),
+    #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+    dct_32768: (32768, dct2_32768, dct3_32768),
+    #[cfg(feature = "maxn-65536")]
+    dct_65536: (65536, dct2_65536, dct3_65536),
+}
+
+#[test]
+fn dct3_definition() {
+    // a single DCT-III basis function
+    let n = 16;
+    let mut input = vec![0.; n];
+    input[3] = 1.;
+    let expected: Vec<_> = (0..n)
+        .map(|i| (2. / n as f64 * (PI * (2 * i + 1) as f64 * 3. / (2. * n as f64)).cos()) as f32)
+        .collect();
+    assert_values_eq(
+        microfft::dct::dct3_16(&mut input),
+        &expected,
+        max_abs(&expected),
+        1e-6,
+    );
+
+    // the DC term is weighted by 1/2
+    let mut input = vec![0.; n];
+    input[0] = 1.;
+    let expected = vec![1. / n as f32; n];
+    assert_values_eq(
+        microfft::dct::dct3_16(&mut input),
+        &expected,
+        max_abs(&expected),
+        1e-6,
+    );
+}
+
+fn naive_dct4(input: &[f32], k: usize) -> f32 {
+    let n = input.len() as f64;
+    let sum: f64 = input
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            f64::from(*x) * (PI * (2 * i + 1) as f64 * (2 * k + 1) as f64 / (4. * n)).cos()
+        })
+        .sum();
+    sum as f32
+}
+
+macro_rules! dct4_tests {
+    ( $( $(#[$meta:meta])* $name:ident: $N:expr, )* ) => {
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() {
+                let input = test_signal($N);
+
+                // compare a bounded number of bins against the O(N^2) DCT
+                let step = ($N / 256).max(1);
+                let mut result = input.clone();
+                let result = microfft::dct::$name(&mut result);
+                let bins: Vec<_> = (0..$N).step_by(step).collect();
+                let expected: Vec<_> = bins.iter().map(|&k| naive_dct4(&input, k)).collect();
+                let sampled: Vec<_> = bins.iter().map(|&k| result[k]).collect();
+                assert_values_eq(&sampled, &expected, max_abs(&expected), 1e-5);
+
+                // the DCT-IV is its own inverse, up to a factor of N/2
+                let restored = microfft::dct::$name(result);
+                let expected: Vec<_> = input.iter().map(|x| x * ($N / 2) as f32).collect();
+                assert_values_eq(restored, &expected, max_abs(&expected), 1e-5);
+            }
+        )*
+    };
+}
+
+dct4_tests! {
+    dct4_2: 2,
+    dct4_4: 4,
+    dct4_8: 8,
+    dct4_16: 16,
+    dct4_32: 32,
+    dct4_64: 64,
+    dct4_128: 128,
+    dct4_256: 256,
+    dct4_512: 512,
+    dct4_1024: 1024,
+    dct4_2048: 2048,
+    dct4_4096: 4096,
+    #[cfg(any(
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    dct4_8192: 8192,
+    #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+    dct4_16384: 16384,
+    #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+    dct4_32768: 32768,
+    #[cfg(feature = "maxn-65536")]
+    dct4_65536: 65536,
+}
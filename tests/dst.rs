@@ -0,0 +1,103 @@
+mod common;
+
+use common::{assert_values_eq, max_abs, test_signal};
+use std::f64::consts::PI;
+
+fn naive_dst1(input: &[f32], k: usize) -> f32 {
+    let n = input.len() as f64;
+    let sum: f64 = input
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, x)| f64::from(*x) * (PI * i as f64 * k as f64 / n).sin())
+        .sum();
+    sum as f32
+}
+
+fn naive_dst2(input: &[f32], k: usize) -> f32 {
+    let n = input.len() as f64;
+    let sum: f64 = input
+        .iter()
+        .enumerate()
+        .map(|(i, x)| f64::from(*x) * (PI * (2 * i + 1) as f64 * (k + 1) as f64 / (2. * n)).sin())
+        .sum();
+    sum as f32
+}
+
+macro_rules! dst_tests {
+    ( $( $(#[$meta:meta])* $name:ident: ($N:expr, $dst1:ident, $dst2:ident), )* ) => {
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() {
+                let input = test_signal($N);
+
+                // compare a bounded number of bins against the O(N^2) DSTs
+                let step = ($N / 256).max(1);
+                let bins: Vec<_> = (0..$N).step_by(step).collect();
+
+                let mut result = input.clone();
+                let result = microfft::dst::$dst1(&mut result);
+                let expected: Vec<_> = bins.iter().map(|&k| naive_dst1(&input, k)).collect();
+                let sampled: Vec<_> = bins.iter().map(|&k| result[k]).collect();
+                let max = max_abs(result);
+                assert_values_eq(&sampled, &expected, max, 1e-5);
+
+                // the DST-I is its own inverse, up to a factor of N/2, but the
+                // rounding errors of its odd-indexed values accumulate
+                let restored = microfft::dst::$dst1(result);
+                let mut expected: Vec<_> = input.iter().map(|x| x * ($N / 2) as f32).collect();
+                expected[0] = 0.;
+                let max = max_abs(&expected);
+                assert_values_eq(restored, &expected, max, 1e-3);
+
+                let mut result = input.clone();
+                let result = microfft::dst::$dst2(&mut result);
+                let expected: Vec<_> = bins.iter().map(|&k| naive_dst2(&input, k)).collect();
+                let sampled: Vec<_> = bins.iter().map(|&k| result[k]).collect();
+                assert_values_eq(&sampled, &expected, max_abs(result), 1e-5);
+            }
+        )*
+    };
+}
+
+dst_tests! {
+    dst_2: (2, dst1_2, dst2_2),
+    dst_4: (4, dst1_4, dst2_4),
+    dst_8: (8, dst1_8, dst2_8),
+    dst_16: (16, dst1_16, dst2_16),
+    dst_32: (32, dst1_32, dst2_32),
+    dst_64: (64, dst1_64, dst2_64),
+    dst_128: (128, dst1_128, dst2_128),
+    dst_256: (256, dst1_256, dst2_256),
+    dst_512: (512, dst1_512, dst2_512),
+    dst_1024: (1024, dst1_1024, dst2_1024),
+    dst_2048: (2048, dst1_2048, dst2_2048),
+    dst_4096: (4096, dst1_4096, dst2_4096),
+    #[cfg(any(
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    dst_8192: (8192, dst1_8192, dst2_8192),
+    #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+    dst_16384: (16384, dst1_16384, dst2_16384),
+    #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+    dst_32768: (32768, dst1_32768, dst2_32768),
+    #[cfg(feature = "maxn-65536")]
+    dst_65536: (65536, dst1_65536, dst2_65536),
+}
+
+#[test]
+fn dst1_sine_series() {
+    // a single sine mode is mapped to a single coefficient
+    let n = 32;
+    let mut input: Vec<_> = (0..n)
+        .map(|i| (PI * 5. * i as f64 / n as f64).sin() as f32)
+        .collect();
+    let mut expected = vec![0.; n];
+    expected[5] = (n / 2) as f32;
+    let result = microfft::dst::dst1_32(&mut input);
+    assert_values_eq(result, &expected, 16., 1e-5);
+}
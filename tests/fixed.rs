@@ -0,0 +1,142 @@
+#![cfg(any(feature = "q15", feature = "q31"))]
+
+mod common;
+
+use common::test_signal;
+use num_complex::{Complex, Complex32};
+use rustfft::{algorithm::Radix4, FFT};
+
+fn rust_fft(input: &[Complex32]) -> Vec<Complex32> {
+    let len = input.len();
+    let fft = Radix4::new(len, false);
+    let mut input = input.to_vec();
+    let mut output = vec![Complex32::default(); len];
+    fft.process(&mut input, &mut output);
+    output
+}
+
+// Scale the shared fixture into the fixed-point range `[-1, 1)`.
+fn test_input(n: usize) -> Vec<Complex32> {
+    test_signal(n)
+        .into_iter()
+        .map(|f| f / 11.)
+        .map(|f| Complex32::new(f, -f / 2.))
+        .collect()
+}
+
+macro_rules! fixed_tests {
+    (
+        $complex:ident, $real:ident, $T:ty, $frac_bits:expr, $tolerance:expr;
+        $( $(#[$meta:meta])* $name:ident: ($N:expr, $cfft:ident, $rfft:ident), )*
+    ) => {
+        fn to_fixed(x: f32) -> $T {
+            (f64::from(x) * (1u64 << $frac_bits) as f64).round() as $T
+        }
+
+        fn from_fixed(x: Complex<$T>) -> Complex32 {
+            let scale = (1u64 << $frac_bits) as f64;
+            Complex32::new((x.re as f64 / scale) as f32, (x.im as f64 / scale) as f32)
+        }
+
+        fn assert_fixed_eq(xa: &[Complex<$T>], xb: &[Complex32]) {
+            assert_eq!(xa.len(), xb.len());
+            for (a, b) in xa.iter().zip(xb) {
+                assert!((from_fixed(*a) - b).norm() < $tolerance);
+            }
+        }
+
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() {
+                let input = test_input($N);
+                let scale = 1. / $N as f32;
+
+                let expected: Vec<_> = rust_fft(&input).iter().map(|c| c * scale).collect();
+                let mut result: Vec<_> = input
+                    .iter()
+                    .map(|c| Complex::new(to_fixed(c.re), to_fixed(c.im)))
+                    .collect();
+                let result = microfft::$complex::$cfft(&mut result);
+                assert_fixed_eq(result, &expected);
+
+                let input_c: Vec<_> = input.iter().map(|c| Complex32::new(c.re, 0.)).collect();
+                let expected: Vec<_> = rust_fft(&input_c)[..($N / 2)]
+                    .iter()
+                    .map(|c| c * scale)
+                    .collect();
+                let mut result: Vec<_> = input.iter().map(|c| to_fixed(c.re)).collect();
+                let result = microfft::$real::$rfft(&mut result);
+                assert_fixed_eq(result, &expected);
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "q15")]
+mod q15 {
+    use super::*;
+
+    fixed_tests! {
+        complex_q15, real_q15, i16, 15, 1e-3;
+        q15_2: (2, cfft_2, rfft_2),
+        q15_4: (4, cfft_4, rfft_4),
+        q15_8: (8, cfft_8, rfft_8),
+        q15_16: (16, cfft_16, rfft_16),
+        q15_32: (32, cfft_32, rfft_32),
+        q15_64: (64, cfft_64, rfft_64),
+        q15_128: (128, cfft_128, rfft_128),
+        q15_256: (256, cfft_256, rfft_256),
+        q15_512: (512, cfft_512, rfft_512),
+        q15_1024: (1024, cfft_1024, rfft_1024),
+        q15_2048: (2048, cfft_2048, rfft_2048),
+        q15_4096: (4096, cfft_4096, rfft_4096),
+        #[cfg(any(
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        q15_8192: (8192, cfft_8192, rfft_8192),
+        #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+        q15_16384: (16384, cfft_16384, rfft_16384),
+        #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+        q15_32768: (32768, cfft_32768, rfft_32768),
+        #[cfg(feature = "maxn-65536")]
+        q15_65536: (65536, cfft_65536, rfft_65536),
+    }
+}
+
+#[cfg(feature = "q31")]
+mod q31 {
+    use super::*;
+
+    fixed_tests! {
+        complex_q31, real_q31, i32, 31, 1e-5;
+        q31_2: (2, cfft_2, rfft_2),
+        q31_4: (4, cfft_4, rfft_4),
+        q31_8: (8, cfft_8, rfft_8),
+        q31_16: (16, cfft_16, rfft_16),
+        q31_32: (32, cfft_32, rfft_32),
+        q31_64: (64, cfft_64, rfft_64),
+        q31_128: (128, cfft_128, rfft_128),
+        q31_256: (256, cfft_256, rfft_256),
+        q31_512: (512, cfft_512, rfft_512),
+        q31_1024: (1024, cfft_1024, rfft_1024),
+        q31_2048: (2048, cfft_2048, rfft_2048),
+        q31_4096: (4096, cfft_4096, rfft_4096),
+        #[cfg(any(
+            feature = "maxn-8192",
+            feature = "maxn-16384",
+            feature = "maxn-32768",
+            feature = "maxn-65536",
+        ))]
+        q31_8192: (8192, cfft_8192, rfft_8192),
+        #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+        q31_16384: (16384, cfft_16384, rfft_16384),
+        #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+        q31_32768: (32768, cfft_32768, rfft_32768),
+        #[cfg(feature = "maxn-65536")]
+        q31_65536: (65536, cfft_65536, rfft_65536),
+    }
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::test_signal;
+use microfft::{goertzel, goertzel::Goertzel, Complex32};
+
+fn dft(input: &[f32], k: usize) -> Complex32 {
+    let n = input.len() as f64;
+    let (re, im) = input.iter().enumerate().fold((0., 0.), |(re, im), (i, x)| {
+        let phi = -2. * std::f64::consts::PI * (k * i) as f64 / n;
+        let x = f64::from(*x);
+        (re + x * phi.cos(), im + x * phi.sin())
+    });
+    Complex32::new(re as f32, im as f32)
+}
+
+// The tolerance is relative to the sum of the input magnitudes, which bounds
+// the magnitude of all bins.
+fn assert_bin_eq(value: Complex32, expected: Complex32, input: &[f32]) {
+    let max: f32 = input.iter().map(|x| x.abs()).sum();
+    assert!((value - expected).norm() <= 1e-4 * max);
+}
+
+#[test]
+fn goertzel_fft_grid() {
+    let mut input = test_signal(64);
+    let expected = microfft::real::rfft_64(&mut input.clone()).to_vec();
+
+    for (k, expected) in expected.iter().enumerate().skip(1) {
+        assert_bin_eq(goertzel::value(&input, k), *expected, &input);
+        let power = goertzel::power(&input, k);
+        assert!((power - expected.norm_sqr()).abs() < 1e-3 * power.max(1.));
+    }
+
+    // bins beyond the Nyquist frequency are conjugate
+    let value = goertzel::value(&input, 60);
+    assert_bin_eq(value, expected[4].conj(), &input);
+
+    input.iter_mut().for_each(|x| *x = 1.);
+    let value = goertzel::value(&input, 0);
+    assert_bin_eq(value, Complex32::new(64., 0.), &input);
+}
+
+#[test]
+fn goertzel_any_size() {
+    // the classic DTMF block size
+    let input = test_signal(205);
+    for &k in &[0, 1, 18, 20, 22, 24, 31, 34, 38, 42, 102] {
+        assert_bin_eq(goertzel::value(&input, k), dft(&input, k), &input);
+    }
+}
+
+#[test]
+fn goertzel_frequency() {
+    let input = test_signal(100);
+    for &k in &[3, 17, 49] {
+        let mut goertzel = Goertzel::with_frequency(k as f32 / 100.);
+        goertzel.extend(&input);
+        assert_bin_eq(goertzel.value(), dft(&input, k), &input);
+    }
+}
+
+#[test]
+fn goertzel_streaming() {
+    let input = test_signal(256);
+    let mut goertzel = Goertzel::new(10, 128);
+
+    for x in &input[..128] {
+        goertzel.push(*x);
+    }
+    let expected = goertzel::value(&input[..128], 10);
+    assert_bin_eq(goertzel.value(), expected, &input[..128]);
+
+    goertzel.reset();
+    goertzel.extend(&input[128..]);
+    let expected = goertzel::value(&input[128..], 10);
+    assert_bin_eq(goertzel.value(), expected, &input[128..]);
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::{assert_values_eq, max_abs, test_signal};
+use std::f64::consts::PI;
+
+fn naive_dht(input: &[f32], k: usize) -> f32 {
+    let n = input.len() as f64;
+    let sum: f64 = input
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let phi = 2. * PI * ((i * k) % input.len()) as f64 / n;
+            f64::from(*x) * (phi.cos() + phi.sin())
+        })
+        .sum();
+    sum as f32
+}
+
+macro_rules! dht_tests {
+    ( $( $(#[$meta:meta])* $name:ident: ($N:expr, $dht:ident, $idht:ident), )* ) => {
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() {
+                let input = test_signal($N);
+
+                // compare a bounded number of bins against the O(N^2) DHT
+                let step = ($N / 256).max(1);
+                let mut result = input.clone();
+                let result = microfft::hartley::$dht(&mut result);
+                let bins: Vec<_> = (0..$N).step_by(step).collect();
+                let expected: Vec<_> = bins.iter().map(|&k| naive_dht(&input, k)).collect();
+                let sampled: Vec<_> = bins.iter().map(|&k| result[k]).collect();
+                assert_values_eq(&sampled, &expected, max_abs(result), 1e-5);
+
+                let restored = microfft::hartley::$idht(result);
+                assert_values_eq(restored, &input, max_abs(&input), 1e-5);
+            }
+        )*
+    };
+}
+
+dht_tests! {
+    dht_2: (2, dht_2, idht_2),
+    dht_4: (4, dht_4, idht_4),
+    dht_8: (8, dht_8, idht_8),
+    dht_16: (16, dht_16, idht_16),
+    dht_32: (32, dht_32, idht_32),
+    dht_64: (64, dht_64, idht_64),
+    dht_128: (128, dht_128, idht_128),
+    dht_256: (256, dht_256, idht_256),
+    dht_512: (512, dht_512, idht_512),
+    dht_1024: (1024, dht_1024, idht_1024),
+    dht_2048: (2048, dht_2048, idht_2048),
+    dht_4096: (4096, dht_4096, idht_4096),
+    #[cfg(any(
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    dht_8192: (8192, dht_8192, idht_8192),
+    #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+    dht_16384: (16384, dht_16384, idht_16384),
+    #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+    dht_32768: (32768, dht_32768, idht_32768),
+    #[cfg(feature = "maxn-65536")]
+    dht_65536: (65536, dht_65536, idht_65536),
+}
+
+#[test]
+fn dht_rfft_relation() {
+    let input = test_signal(64);
+    let spectrum = microfft::real::rfft_packed_64(&mut input.clone()).to_vec();
+    let mut result = input;
+    let result = microfft::hartley::dht_64(&mut result);
+
+    assert_eq!(result[0], spectrum[0].re);
+    assert_eq!(result[32], spectrum[0].im);
+    for (k, f) in spectrum.iter().enumerate().skip(1) {
+        assert_eq!(result[k], f.re - f.im);
+        assert_eq!(result[64 - k], f.re + f.im);
+    }
+}
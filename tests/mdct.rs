@@ -0,0 +1,116 @@
+mod common;
+
+use common::{assert_values_eq, max_abs, test_signal};
+use std::f64::consts::PI;
+
+fn sine_window(n: usize) -> Vec<f32> {
+    (0..n)
+        .map(|i| (PI * (i as f64 + 0.5) / n as f64).sin() as f32)
+        .collect()
+}
+
+fn naive_mdct(input: &[f32], window: &[f32], k: usize) -> f32 {
+    let m = (input.len() / 2) as f64;
+    let sum: f64 = input
+        .iter()
+        .zip(window)
+        .enumerate()
+        .map(|(i, (x, w))| {
+            let phi = PI / m * (i as f64 + 0.5 + m / 2.) * (k as f64 + 0.5);
+            f64::from(*x) * f64::from(*w) * phi.cos()
+        })
+        .sum();
+    sum as f32
+}
+
+macro_rules! mdct_tests {
+    ( $( $(#[$meta:meta])* $name:ident: ($N:expr, $mdct:ident, $imdct:ident), )* ) => {
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() {
+                let n = $N;
+                let m = n / 2;
+                let signal = test_signal(4 * m);
+                let window = sine_window(n);
+
+                // compare a bounded number of coefficients against the O(N^2) MDCT
+                let step = (m / 128).max(1);
+                let mut coeffs = vec![0.; m];
+                microfft::mdct::$mdct(&signal[..n], &window, &mut coeffs);
+                let bins: Vec<_> = (0..m).step_by(step).collect();
+                let expected: Vec<_> = bins
+                    .iter()
+                    .map(|&k| naive_mdct(&signal[..n], &window, k))
+                    .collect();
+                let sampled: Vec<_> = bins.iter().map(|&k| coeffs[k]).collect();
+                assert_values_eq(&sampled, &expected, max_abs(&coeffs), 1e-5);
+
+                // overlap-add cancels the aliasing of all but the first block
+                let mut overlap = vec![0.; m];
+                let mut output = Vec::new();
+                for block in signal.windows(n).step_by(m) {
+                    microfft::mdct::$mdct(block, &window, &mut coeffs);
+                    let result = microfft::mdct::$imdct(&mut coeffs, &window, &mut overlap);
+                    output.extend_from_slice(result);
+                }
+                let expected = &signal[m..3 * m];
+                assert_values_eq(&output[m..], expected, max_abs(expected), 1e-5);
+            }
+        )*
+    };
+}
+
+mdct_tests! {
+    mdct_8: (8, mdct_8, imdct_8),
+    mdct_16: (16, mdct_16, imdct_16),
+    mdct_32: (32, mdct_32, imdct_32),
+    mdct_64: (64, mdct_64, imdct_64),
+    mdct_128: (128, mdct_128, imdct_128),
+    mdct_256: (256, mdct_256, imdct_256),
+    mdct_512: (512, mdct_512, imdct_512),
+    mdct_1024: (1024, mdct_1024, imdct_1024),
+    mdct_2048: (2048, mdct_2048, imdct_2048),
+    mdct_4096: (4096, mdct_4096, imdct_4096),
+    #[cfg(any(
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    mdct_8192: (8192, mdct_8192, imdct_8192),
+    #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+    mdct_16384: (16384, mdct_16384, imdct_16384),
+    #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+    mdct_32768: (32768, mdct_32768, imdct_32768),
+    #[cfg(feature = "maxn-65536")]
+    mdct_65536: (65536, mdct_65536, imdct_65536),
+}
+
+#[test]
+fn imdct_overlap() {
+    // with a zero overlap, the first half of the output is the windowed
+    // inverse transform
+    let window = sine_window(16);
+    let mut coeffs: Vec<_> = test_signal(8);
+    let mut overlap = vec![0.; 8];
+    let input = coeffs.clone();
+    let result = microfft::mdct::imdct_16(&mut coeffs, &window, &mut overlap);
+
+    let expected: Vec<_> = (0..16)
+        .map(|i| {
+            let sum: f64 = input
+                .iter()
+                .enumerate()
+                .map(|(k, x)| {
+                    let phi = PI / 8. * (i as f64 + 0.5 + 4.) * (k as f64 + 0.5);
+                    f64::from(*x) * phi.cos()
+                })
+                .sum();
+            (sum * 2. / 8. * f64::from(window[i])) as f32
+        })
+        .collect();
+    let max = max_abs(&expected);
+    assert_values_eq(result, &expected[..8], max, 1e-6);
+    assert_values_eq(&overlap, &expected[8..], max, 1e-6);
+}
@@ -0,0 +1,119 @@
+mod common;
+
+use common::{assert_values_eq, max_abs, test_signal};
+use microfft::{psd::Welch, window::Window};
+use std::f32::consts::PI;
+
+const RECTANGULAR: Window = Window::Kaiser { beta: 0. };
+
+fn sinusoid(len: usize, amplitude: f32, freq: f32, sample_rate: f32) -> Vec<f32> {
+    (0..len)
+        .map(|i| amplitude * (2. * PI * freq * i as f32 / sample_rate).sin())
+        .collect()
+}
+
+#[test]
+fn welch_parseval() {
+    // with a rectangular window, the integrated PSD of a segment equals its
+    // mean power
+    let signal = test_signal(64);
+    let mean_power = signal.iter().map(|x| x * x).sum::<f32>() / 64.;
+
+    let mut welch = Welch::<64>::new(RECTANGULAR, 8000.);
+    let mut psd = [f32::NAN; 33];
+    welch.push(&signal, &mut psd);
+
+    let bin_width = welch.frequency(1);
+    assert_eq!(bin_width, 125.);
+    let power = psd.iter().sum::<f32>() * bin_width;
+    assert!((power - mean_power).abs() <= mean_power * 1e-5);
+}
+
+#[test]
+fn welch_sinusoid() {
+    let sample_rate = 8000.;
+    let mut signal = sinusoid(64, 2., 1000., sample_rate);
+    signal.iter_mut().for_each(|x| *x += 0.5);
+
+    let mut welch = Welch::<64>::new(RECTANGULAR, sample_rate);
+    let mut psd = [f32::NAN; 33];
+    welch.push(&signal, &mut psd);
+
+    // the sinusoid lies at bin 8, DC is not doubled
+    let bin_width = welch.frequency(1);
+    assert!((psd[8] * bin_width - 2.).abs() < 1e-4);
+    assert!((psd[0] * bin_width - 0.25).abs() < 1e-4);
+}
+
+#[test]
+fn welch_nyquist() {
+    let signal: Vec<_> = (0..32).map(|i| if i % 2 == 0 { 1. } else { -1. }).collect();
+    let mut welch = Welch::<32>::new(RECTANGULAR, 1.);
+    let mut psd = [0.; 17];
+    welch.push(&signal, &mut psd);
+
+    assert_eq!(welch.frequency(16), 0.5);
+    assert!((psd[16] / 32. - 1.).abs() < 1e-5);
+    assert!(psd[..16].iter().all(|p| p.abs() < 1e-6));
+}
+
+#[test]
+fn welch_average() {
+    let a = test_signal(128);
+    let b = sinusoid(128, 3., 20., 128.);
+
+    let mut welch = Welch::<128>::new(Window::Hann, 128.);
+    let (mut psd_a, mut psd_b) = ([0.; 65], [0.; 65]);
+    welch.push(&a, &mut psd_a);
+    welch.reset();
+    welch.push(&b, &mut psd_b);
+
+    welch.reset();
+    let mut psd = [0.; 65];
+    welch.push(&a, &mut psd);
+    welch.push(&b, &mut psd);
+    welch.push(&a, &mut psd);
+    assert_eq!(welch.segments(), 3);
+
+    let expected: Vec<_> = psd_a
+        .iter()
+        .zip(&psd_b)
+        .map(|(a, b)| (2. * a + b) / 3.)
+        .collect();
+    assert_values_eq(&psd, &expected, max_abs(&expected), 1e-5);
+}
+
+#[test]
+fn welch_overlapping() {
+    let signal = sinusoid(1000, 1., 50., 1000.);
+
+    let mut welch = Welch::<64>::new(Window::Hann, 1000.);
+    let mut psd = [0.; 33];
+    assert_eq!(welch.push_overlapping(&signal, 32, &mut psd), 30);
+    assert_eq!(welch.segments(), 30);
+
+    let mut expected = [0.; 33];
+    let mut reference = Welch::<64>::new(Window::Hann, 1000.);
+    for start in (0..=936).step_by(32) {
+        reference.push(&signal[start..start + 64], &mut expected);
+    }
+    assert_eq!(psd, expected);
+
+    // the integrated PSD approximates the power of the sinusoid
+    let power = psd.iter().sum::<f32>() * welch.frequency(1);
+    assert!((power - 0.5).abs() < 0.01);
+}
+
+#[test]
+#[should_panic]
+fn welch_psd_size() {
+    let mut welch = Welch::<16>::new(Window::Hann, 1.);
+    welch.push(&[0.; 16], &mut [0.; 8]);
+}
+
+#[test]
+#[should_panic]
+fn welch_overlap_too_large() {
+    let mut welch = Welch::<16>::new(Window::Hann, 1.);
+    welch.push_overlapping(&[0.; 64], 16, &mut [0.; 9]);
+}
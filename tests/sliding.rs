@@ -0,0 +1,75 @@
+mod common;
+
+use common::test_signal;
+use microfft::{sliding::SlidingDft, Complex32};
+
+fn window_spectrum(window: &[f32]) -> Vec<Complex32> {
+    let mut input: Vec<_> = window.iter().map(|x| Complex32::new(*x, 0.)).collect();
+    microfft::complex::try_cfft(&mut input).unwrap().to_vec()
+}
+
+fn assert_bins_eq(xa: &[Complex32], xb: &[Complex32]) {
+    assert_eq!(xa.len(), xb.len());
+    for (a, b) in xa.iter().zip(xb) {
+        assert!((a - b).norm() < 1e-2);
+    }
+}
+
+#[test]
+fn sliding_all_bins() {
+    let signal = test_signal(1000);
+    let mut sdft = SlidingDft::<64>::new();
+    for (i, x) in signal.iter().enumerate() {
+        sdft.push(*x);
+        if i >= 63 {
+            assert_bins_eq(sdft.bins(), &window_spectrum(&signal[(i - 63)..=i]));
+        }
+    }
+}
+
+#[test]
+fn sliding_selected_bins() {
+    let signal = test_signal(1000);
+    let mut sdft = SlidingDft::<256>::with_bins(10..20);
+    signal.iter().for_each(|x| sdft.push(*x));
+
+    let expected = window_spectrum(&signal[744..]);
+    assert_bins_eq(sdft.bins(), &expected[10..20]);
+    assert_eq!(sdft.bin(15), sdft.bins()[5]);
+}
+
+#[test]
+fn sliding_resync() {
+    let signal = test_signal(10_000);
+    let mut sdft = SlidingDft::<32>::new();
+    sdft.set_resync_interval(0);
+    signal.iter().for_each(|x| sdft.push(*x));
+
+    let expected = window_spectrum(&signal[(10_000 - 32)..]);
+    sdft.resync();
+    assert_bins_eq(sdft.bins(), &expected);
+}
+
+#[test]
+fn sliding_resync_reenabled() {
+    let signal = test_signal(100_100);
+    let mut sdft = SlidingDft::<32>::new();
+    sdft.set_resync_interval(0);
+    signal[..100].iter().for_each(|x| sdft.push(*x));
+
+    sdft.set_resync_interval(32);
+    signal[100..].iter().for_each(|x| sdft.push(*x));
+
+    // the last push triggered a resync, so there is no drift
+    let mut resynced = sdft.clone();
+    resynced.resync();
+    assert_eq!(sdft.bins(), resynced.bins());
+    assert_bins_eq(sdft.bins(), &window_spectrum(&signal[(100_100 - 32)..]));
+}
+
+#[test]
+#[should_panic]
+fn sliding_bin_out_of_range() {
+    let sdft = SlidingDft::<32>::with_bins(0..8);
+    sdft.bin(8);
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::{assert_values_eq, max_abs, test_signal};
+use microfft::{spectrum, Complex32};
+use std::f32::consts::PI;
+
+// amplitudes whose squares are within the normal range of `f32`
+fn amplitude_range() -> Vec<f32> {
+    (-60..60)
+        .flat_map(|e| (0..7).map(move |i| 2_f32.powi(e) * (1. + i as f32 / 7.)))
+        .collect()
+}
+
+// Construct RFFT output whose inner bins have the given amplitudes.
+fn bins_with_amplitudes(amplitudes: &[f32]) -> Vec<Complex32> {
+    let n = (amplitudes.len() + 1) * 2;
+    let scale = n as f64 / 2.;
+    let mut bins = vec![Complex32::default()];
+    bins.extend(amplitudes.iter().map(|a| {
+        Complex32::new(
+            (f64::from(*a) * 0.6 * scale) as f32,
+            (f64::from(*a) * 0.8 * scale) as f32,
+        )
+    }));
+    bins
+}
+
+#[test]
+fn spectrum_magnitude_accuracy() {
+    let values = amplitude_range();
+    let mut bins = bins_with_amplitudes(&values);
+    let result = spectrum::magnitude(&mut bins);
+    for (r, v) in result[1..].iter().zip(&values) {
+        assert!(((r - v) / v).abs() < 1e-6, "{} != {}", r, v);
+    }
+}
+
+#[test]
+fn spectrum_db_accuracy() {
+    let values = amplitude_range();
+    let mut bins = bins_with_amplitudes(&values);
+    let result = spectrum::db(&mut bins);
+    for (r, v) in result[1..].iter().zip(&values) {
+        let expected = 20. * f64::from(*v).log10();
+        assert!(
+            (f64::from(*r) - expected).abs() < 1e-4,
+            "{} != {}",
+            r,
+            expected
+        );
+    }
+    assert_eq!(result[0], f32::NEG_INFINITY);
+}
+
+#[test]
+fn spectrum_sinusoids() {
+    // DC, a sinusoid and the Nyquist term, each with a different amplitude
+    let n = 64;
+    let mut samples: Vec<_> = (0..n)
+        .map(|i| {
+            let t = i as f32 / n as f32;
+            0.5 + 2. * (2. * PI * 5. * t).cos() + 3. * (2. * PI * 7. * t).sin()
+                - 0.25 * (PI * i as f32).cos()
+        })
+        .collect();
+    let mean_power = samples.iter().map(|x| x * x).sum::<f32>() / n as f32;
+
+    let bins = microfft::real::rfft_packed_64(&mut samples).to_vec();
+
+    let mut amplitudes = bins.clone();
+    let amplitudes = spectrum::magnitude(&mut amplitudes);
+    assert_eq!(amplitudes.len(), n / 2 + 1);
+    for (k, expected) in [(0, 0.5), (5, 2.), (7, 3.), (32, 0.25)].iter() {
+        assert!((amplitudes[*k] - expected).abs() < 1e-5);
+    }
+
+    let mut power = bins.clone();
+    let power = spectrum::power(&mut power);
+    assert!((power[0] - 0.25).abs() < 1e-5);
+    assert!((power[5] - 2.).abs() < 1e-5);
+    assert!((power[7] - 4.5).abs() < 1e-5);
+    assert!((power[32] - 0.0625).abs() < 1e-5);
+    assert!((power.iter().sum::<f32>() - mean_power).abs() < 1e-4);
+
+    let mut levels = bins;
+    let levels = spectrum::db(&mut levels);
+    assert!((levels[5] - 6.0206).abs() < 1e-3);
+    assert!((levels[32] + 12.0412).abs() < 1e-3);
+}
+
+#[test]
+fn spectrum_parseval() {
+    let mut samples = test_signal(64);
+    let mean_power = samples.iter().map(|x| x * x).sum::<f32>() / 64.;
+
+    let bins = microfft::real::rfft_packed_64(&mut samples).to_vec();
+
+    let mut power = bins.clone();
+    let power = spectrum::power(&mut power);
+    assert!((power.iter().sum::<f32>() - mean_power).abs() <= mean_power * 1e-5);
+
+    // the power is the squared amplitude, except for the doubled inner bins
+    let mut amplitudes = bins;
+    let amplitudes = spectrum::magnitude(&mut amplitudes);
+    let expected: Vec<_> = amplitudes
+        .iter()
+        .enumerate()
+        .map(|(k, a)| if k == 0 || k == 32 { a * a } else { a * a / 2. })
+        .collect();
+    assert_values_eq(power, &expected, max_abs(&expected), 1e-5);
+}
+
+#[test]
+fn spectrum_plain_rfft() {
+    // the plain RFFT drops the Nyquist term
+    let mut samples: Vec<_> = (0..16).map(|i| if i % 2 == 0 { 1. } else { -1. }).collect();
+    let bins = microfft::real::rfft_16(&mut samples);
+    let power = spectrum::power(bins);
+    assert_eq!(power.len(), 9);
+    assert!(power.iter().all(|p| *p == 0.));
+}
+
+#[test]
+fn spectrum_empty() {
+    assert!(spectrum::magnitude(&mut []).is_empty());
+}
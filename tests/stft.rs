@@ -0,0 +1,206 @@
+mod common;
+
+use common::test_signal;
+use microfft::{
+    stft::{Istft, Stft},
+    window::Window,
+    Complex32,
+};
+
+fn expected_frame(signal: &[f32], window: Window) -> Vec<Complex32> {
+    let n = signal.len();
+    let mut frame: Vec<_> = signal
+        .iter()
+        .enumerate()
+        .map(|(i, x)| x * window.value(i, n))
+        .collect();
+    microfft::real::rfft_packed_32(&mut frame).to_vec()
+}
+
+fn assert_frame_eq(frame: &[Complex32], expected: &[Complex32]) {
+    for (f, e) in frame.iter().zip(expected) {
+        assert!((f - e).norm() < 1e-4, "{} != {}", f, e);
+    }
+}
+
+#[test]
+fn stft_frames() {
+    let signal = test_signal(300);
+    for &hop in &[1, 5, 8, 32] {
+        let mut stft = Stft::<32>::new(Window::Hann, hop);
+        assert_eq!(stft.hop(), hop);
+
+        let mut starts = Vec::new();
+        for (i, &x) in signal.iter().enumerate() {
+            if let Some(frame) = stft.push(x) {
+                let start = i + 1 - 32;
+                assert_frame_eq(frame, &expected_frame(&signal[start..=i], Window::Hann));
+                starts.push(start);
+            }
+        }
+
+        let expected: Vec<_> = (0..=300 - 32).step_by(hop).collect();
+        assert_eq!(starts, expected);
+    }
+}
+
+#[test]
+fn stft_blocks() {
+    // pushing blocks of samples yields the same frames as single samples
+    let signal = test_signal(500);
+    let mut expected = Vec::new();
+    let mut stft = Stft::<32>::new(Window::Blackman, 12);
+    for &x in &signal {
+        if let Some(frame) = stft.push(x) {
+            expected.push(frame.to_vec());
+        }
+    }
+
+    let mut frames = Vec::new();
+    let mut stft = Stft::<32>::new(Window::Blackman, 12);
+    for block in signal.chunks(17) {
+        stft.process(block, |frame| frames.push(frame.to_vec()));
+    }
+    assert_eq!(frames, expected);
+}
+
+#[test]
+fn stft_reset() {
+    let signal = test_signal(100);
+    let mut stft = Stft::<32>::new(Window::Hann, 8);
+    stft.process(&signal[..50], |_| {});
+    stft.reset();
+
+    let mut frames = Vec::new();
+    stft.process(&signal[50..], |frame| frames.push(frame.to_vec()));
+    assert_eq!(frames.len(), 3);
+    assert_frame_eq(&frames[0], &expected_frame(&signal[50..82], Window::Hann));
+}
+
+#[test]
+#[should_panic]
+fn stft_hop_zero() {
+    Stft::<32>::new(Window::Hann, 0);
+}
+
+#[test]
+#[should_panic]
+fn stft_hop_too_large() {
+    Stft::<32>::new(Window::Hann, 33);
+}
+
+fn roundtrip(signal: &[f32], window: Window, hop: usize) -> Vec<f32> {
+    let mut stft = Stft::<32>::new(window, hop);
+    let mut istft = Istft::<32>::new(window, hop);
+    assert_eq!(istft.hop(), hop);
+
+    let mut output = Vec::new();
+    stft.process(signal, |frame| output.extend_from_slice(istft.push(frame)));
+    output
+}
+
+/// Return the expected output of the inverse STFT, where samples covered only
+/// by the edges of the window are dropped.
+fn reconstructed(signal: &[f32], len: usize, window: Window, hop: usize) -> Vec<f32> {
+    let mut weights = vec![0.; len];
+    for start in (0..len).step_by(hop) {
+        for (i, w) in weights[start..].iter_mut().take(32).enumerate() {
+            *w += window.value(i, 32).powi(2);
+        }
+    }
+
+    let min_weight = weights.iter().cloned().fold(0., f32::max) * 1e-3;
+    signal
+        .iter()
+        .zip(&weights)
+        .map(|(x, w)| if *w < min_weight { 0. } else { *x })
+        .collect()
+}
+
+#[test]
+fn istft_reconstruction() {
+    let signal = test_signal(400);
+    let windows = [
+        Window::Hann,
+        Window::Hamming,
+        Window::Blackman,
+        Window::BlackmanHarris,
+        Window::Kaiser { beta: 6. },
+    ];
+    for &window in &windows {
+        for &hop in &[1, 4, 8, 16, 24] {
+            let output = roundtrip(&signal, window, hop);
+            assert_eq!(output.len(), (400 - 32) / hop * hop + hop);
+
+            let expected = reconstructed(&signal, output.len(), window, hop);
+            for (y, x) in output.iter().zip(&expected) {
+                assert!(
+                    (y - x).abs() < 1e-4,
+                    "{:?}, {}: {} != {}",
+                    window,
+                    hop,
+                    y,
+                    x
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn istft_zero_weight() {
+    // with a hop of `N`, the Hann window is zero at the start of each frame
+    let signal = test_signal(128);
+    let output = roundtrip(&signal, Window::Hann, 32);
+    let expected = reconstructed(&signal, output.len(), Window::Hann, 32);
+    for (i, (y, x)) in output.iter().zip(&expected).enumerate() {
+        if i % 32 == 0 {
+            assert_eq!(*x, 0.);
+        }
+        assert!((y - x).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn istft_modified_frames() {
+    // removing everything but DC leaves the mean of each frame
+    let signal: Vec<_> = test_signal(256).iter().map(|x| x + 3.).collect();
+    let mut stft = Stft::<32>::new(Window::Kaiser { beta: 0. }, 32);
+    let mut istft = Istft::<32>::new(Window::Kaiser { beta: 0. }, 32);
+
+    let mut output = Vec::new();
+    stft.process(&signal, |frame| {
+        frame
+            .iter_mut()
+            .skip(1)
+            .for_each(|c| *c = Complex32::default());
+        frame[0].im = 0.;
+        output.extend_from_slice(istft.push(frame));
+    });
+
+    for (block, input) in output.chunks(32).zip(signal.chunks(32)) {
+        let mean = input.iter().sum::<f32>() / 32.;
+        assert!(block.iter().all(|y| (y - mean).abs() < 1e-4));
+    }
+}
+
+#[test]
+fn istft_reset() {
+    let signal = test_signal(200);
+    let mut stft = Stft::<32>::new(Window::Hann, 8);
+    let mut istft = Istft::<32>::new(Window::Hann, 8);
+    stft.process(&signal[..100], |frame| {
+        istft.push(frame);
+    });
+
+    stft.reset();
+    istft.reset();
+    let mut output = Vec::new();
+    stft.process(&signal[100..], |frame| {
+        output.extend_from_slice(istft.push(frame))
+    });
+    let expected = reconstructed(&signal[100..], output.len(), Window::Hann, 8);
+    for (y, x) in output.iter().zip(&expected) {
+        assert!((y - x).abs() < 1e-4);
+    }
+}
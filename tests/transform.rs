@@ -1,9 +1,21 @@
+mod common;
+
+use common::{assert_values_eq, max_abs, test_signal};
+use microfft::Scaling;
 use num_complex::Complex32;
 use rustfft::{algorithm::Radix4, FFT};
 
 fn rust_fft(input: &[Complex32]) -> Vec<Complex32> {
+    rust_fft_dir(input, false)
+}
+
+fn rust_ifft(input: &[Complex32]) -> Vec<Complex32> {
+    rust_fft_dir(input, true)
+}
+
+fn rust_fft_dir(input: &[Complex32], inverse: bool) -> Vec<Complex32> {
     let len = input.len();
-    let fft = Radix4::new(len, false);
+    let fft = Radix4::new(len, inverse);
     let mut input = input.to_vec();
     let mut output = vec![Complex32::default(); len];
     fft.process(&mut input, &mut output);
@@ -22,11 +34,35 @@ fn approx_eq(a: Complex32, b: Complex32) -> bool {
 
 fn assert_approx_eq(xa: &[Complex32], xb: &[Complex32]) {
     assert_eq!(xa.len(), xb.len());
-    for (a, b) in xa.into_iter().zip(xb) {
+    for (a, b) in xa.iter().zip(xb) {
         assert!(approx_eq(*a, *b));
     }
 }
 
+/// Complex test input with the real parts taken from `test_signal`.
+fn test_input(n: usize) -> Vec<Complex32> {
+    test_signal(n)
+        .into_iter()
+        .map(|f| Complex32::new(f, -f / 2.))
+        .collect()
+}
+
+fn assert_abs_eq(xa: &[Complex32], xb: &[Complex32]) {
+    assert_eq!(xa.len(), xb.len());
+    for (a, b) in xa.iter().zip(xb) {
+        assert!((a - b).norm() < 0.02);
+    }
+}
+
+/// Compare spectra with a tolerance relative to the largest magnitude of `xb`.
+fn assert_spectrum_eq(xa: &[Complex32], xb: &[Complex32]) {
+    assert_eq!(xa.len(), xb.len());
+    let max = xb.iter().map(|c| c.norm()).fold(0., f32::max);
+    for (a, b) in xa.iter().zip(xb) {
+        assert!((a - b).norm() <= max * 1e-5);
+    }
+}
+
 macro_rules! cfft_tests {
     ( $( $name:ident: $N:expr, )* ) => {
         $(
@@ -61,6 +97,47 @@ cfft_tests! {
     cfft_4096: 4096,
 }
 
+macro_rules! icfft_tests {
+    ( $( $name:ident: ($N:expr, $cfft_name:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input: Vec<_> = (0..$N)
+                    .map(|i| i as f32)
+                    .map(|f| Complex32::new(f, -f))
+                    .collect();
+
+                let expected = rust_ifft(&input);
+                let mut unscaled = input.clone();
+                let result = microfft::complex::$name(&mut unscaled, Scaling::Unscaled);
+
+                assert_approx_eq(result, &expected);
+
+                let mut roundtrip = input.clone();
+                microfft::complex::$cfft_name(&mut roundtrip);
+                let result = microfft::complex::$name(&mut roundtrip, Scaling::Normalized);
+
+                assert_approx_eq(&input, result);
+            }
+        )*
+    };
+}
+
+icfft_tests! {
+    icfft_2: (2, cfft_2),
+    icfft_4: (4, cfft_4),
+    icfft_8: (8, cfft_8),
+    icfft_16: (16, cfft_16),
+    icfft_32: (32, cfft_32),
+    icfft_64: (64, cfft_64),
+    icfft_128: (128, cfft_128),
+    icfft_256: (256, cfft_256),
+    icfft_512: (512, cfft_512),
+    icfft_1024: (1024, cfft_1024),
+    icfft_2048: (2048, cfft_2048),
+    icfft_4096: (4096, cfft_4096),
+}
+
 macro_rules! rfft_tests {
     ( $( $name:ident: ($N:expr, $cfft_name:ident), )* ) => {
         $(
@@ -73,6 +150,14 @@ macro_rules! rfft_tests {
                 let result = microfft::real::$name(&mut input);
 
                 assert_approx_eq(result, &expected[..($N / 2)]);
+
+                let mut input = test_signal($N);
+                let mut input_c: Vec<_> = input.iter().map(|f| Complex32::new(*f, 0.)).collect();
+
+                let expected = microfft::complex::$cfft_name(&mut input_c);
+                let result = microfft::real::$name(&mut input);
+
+                assert_abs_eq(result, &expected[..($N / 2)]);
             }
         )*
     };
@@ -92,3 +177,365 @@ rfft_tests! {
     rfft_2048: (2048, cfft_2048),
     rfft_4096: (4096, cfft_4096),
 }
+
+#[test]
+fn rfft_quarter_bin() {
+    // the term at index `N/4` used to be multiplied by `-i` instead of being
+    // conjugated, which only gives the right result if `re == im` there
+    for &n in &[4, 8, 16, 64, 1024, 4096] {
+        let mut input = test_signal(n);
+        let input_c: Vec<_> = input.iter().map(|f| Complex32::new(*f, 0.)).collect();
+
+        let expected = rust_fft(&input_c);
+        let result = microfft::real::try_rfft(&mut input).unwrap();
+        assert_spectrum_eq(result, &expected[..(n / 2)]);
+    }
+}
+
+macro_rules! rfft_packed_tests {
+    ( $( $name:ident: ($N:expr, $cfft_name:ident, $irfft_name:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let mut input: Vec<_> = (5..($N+5)).map(|i| i as f32).collect();
+                let mut input_c: Vec<_> = input.iter().map(|f| Complex32::new(*f, 0.)).collect();
+
+                let spectrum = microfft::complex::$cfft_name(&mut input_c);
+                let mut expected = spectrum[..($N / 2)].to_vec();
+                expected[0].im = spectrum[$N / 2].re;
+
+                let original = input.clone();
+                let result = microfft::real::$name(&mut input);
+
+                assert_approx_eq(result, &expected);
+
+                let result = microfft::real::$irfft_name(result);
+
+                for (a, b) in original.iter().zip(result.iter()) {
+                    assert!((a - b).abs() < 0.02);
+                }
+            }
+        )*
+    };
+}
+
+rfft_packed_tests! {
+    rfft_packed_2: (2, cfft_2, irfft_2),
+    rfft_packed_4: (4, cfft_4, irfft_4),
+    rfft_packed_8: (8, cfft_8, irfft_8),
+    rfft_packed_16: (16, cfft_16, irfft_16),
+    rfft_packed_32: (32, cfft_32, irfft_32),
+    rfft_packed_64: (64, cfft_64, irfft_64),
+    rfft_packed_128: (128, cfft_128, irfft_128),
+    rfft_packed_256: (256, cfft_256, irfft_256),
+    rfft_packed_512: (512, cfft_512, irfft_512),
+    rfft_packed_1024: (1024, cfft_1024, irfft_1024),
+    rfft_packed_2048: (2048, cfft_2048, irfft_2048),
+    rfft_packed_4096: (4096, cfft_4096, irfft_4096),
+}
+
+macro_rules! irfft_tests {
+    ( $( $name:ident: ($N:expr, $cfft_name:ident), )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let expected = test_signal($N);
+                let mut input_c: Vec<_> = expected.iter().map(|f| Complex32::new(*f, 0.)).collect();
+
+                let spectrum = microfft::complex::$cfft_name(&mut input_c);
+                let mut input = spectrum[..($N / 2)].to_vec();
+                input[0].im = spectrum[$N / 2].re;
+
+                let result = microfft::real::$name(&mut input);
+
+                assert_values_eq(result, &expected, max_abs(&expected), 1e-5);
+            }
+        )*
+    };
+}
+
+irfft_tests! {
+    irfft_2: (2, cfft_2),
+    irfft_4: (4, cfft_4),
+    irfft_8: (8, cfft_8),
+    irfft_16: (16, cfft_16),
+    irfft_32: (32, cfft_32),
+    irfft_64: (64, cfft_64),
+    irfft_128: (128, cfft_128),
+    irfft_256: (256, cfft_256),
+    irfft_512: (512, cfft_512),
+    irfft_1024: (1024, cfft_1024),
+    irfft_2048: (2048, cfft_2048),
+    irfft_4096: (4096, cfft_4096),
+}
+
+// Large FFT sizes are tested on bounded inputs, with the tolerance relative to
+// the largest spectrum magnitude, to account for the limited precision of
+// `f32` values.
+macro_rules! large_fft_tests {
+    ( $( $(#[$meta:meta])* $name:ident: ($N:expr, $cfft:ident, $icfft:ident, $rfft:ident, $rfft_packed:ident, $irfft:ident), )* ) => {
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() {
+                let input = test_input($N);
+
+                let expected = rust_fft(&input);
+                let mut result = input.clone();
+                let result = microfft::complex::$cfft(&mut result);
+                assert_spectrum_eq(result, &expected);
+
+                let result = microfft::complex::$icfft(result, Scaling::Normalized);
+                assert_abs_eq(result, &input);
+
+                let mut real: Vec<_> = input.iter().map(|c| c.re).collect();
+                let input_c: Vec<_> = real.iter().map(|f| Complex32::new(*f, 0.)).collect();
+                let spectrum = rust_fft(&input_c);
+                let original = real.clone();
+
+                let result = microfft::real::$rfft(&mut real);
+                assert_spectrum_eq(result, &spectrum[..($N / 2)]);
+
+                let mut real = original.clone();
+                let mut expected = spectrum[..($N / 2)].to_vec();
+                expected[0].im = spectrum[$N / 2].re;
+                let result = microfft::real::$rfft_packed(&mut real);
+                assert_spectrum_eq(result, &expected);
+
+                let result = microfft::real::$irfft(result);
+                assert_values_eq(result, &original, max_abs(&original), 1e-4);
+            }
+        )*
+    };
+}
+
+large_fft_tests! {
+    #[cfg(any(
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    fft_8192: (8192, cfft_8192, icfft_8192, rfft_8192, rfft_packed_8192, irfft_8192),
+    #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+    fft_16384: (16384, cfft_16384, icfft_16384, rfft_16384, rfft_packed_16384, irfft_16384),
+    #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+    fft_32768: (32768, cfft_32768, icfft_32768, rfft_32768, rfft_packed_32768, irfft_32768),
+    #[cfg(feature = "maxn-65536")]
+    fft_65536: (65536, cfft_65536, icfft_65536, rfft_65536, rfft_packed_65536, irfft_65536),
+}
+
+fn rust_fft_any(input: &[Complex32]) -> Vec<Complex32> {
+    let len = input.len();
+    let fft = rustfft::FFTplanner::new(false).plan_fft(len);
+    let mut input = input.to_vec();
+    let mut output = vec![Complex32::default(); len];
+    fft.process(&mut input, &mut output);
+    output
+}
+
+macro_rules! mixed_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                let input = test_input($N);
+
+                let expected = rust_fft_any(&input);
+                let mut result = input.clone();
+                let result = microfft::complex::cfft_mixed(&mut result).unwrap();
+                assert_spectrum_eq(result, &expected);
+
+                let result = microfft::complex::icfft_mixed(result, Scaling::Normalized).unwrap();
+                assert_abs_eq(result, &input);
+            }
+        )*
+    };
+}
+
+mixed_tests! {
+    mixed_3: 3,
+    mixed_5: 5,
+    mixed_6: 6,
+    mixed_15: 15,
+    mixed_64: 64,
+    mixed_225: 225,
+    mixed_243: 243,
+    mixed_480: 480,
+    mixed_625: 625,
+    mixed_960: 960,
+    mixed_1920: 1920,
+    mixed_2880: 2880,
+    mixed_4800: 4800,
+}
+
+#[test]
+fn mixed_errors() {
+    use microfft::{complex::cfft_mixed, FftError};
+
+    let mut input = vec![Complex32::default(); 3 * 131072];
+    assert_eq!(cfft_mixed(&mut input[..0]), Err(FftError::TooSmall));
+    assert_eq!(cfft_mixed(&mut input[..1]), Err(FftError::TooSmall));
+    assert_eq!(
+        cfft_mixed(&mut input[..7]),
+        Err(FftError::UnsupportedFactor)
+    );
+    assert_eq!(
+        cfft_mixed(&mut input[..(3 * 7 * 16)]),
+        Err(FftError::UnsupportedFactor)
+    );
+    assert_eq!(
+        cfft_mixed(&mut input[..(3 * 131072)]),
+        Err(FftError::TooLarge)
+    );
+}
+
+macro_rules! any_tests {
+    ( $( $name:ident: $N:expr, )* ) => {
+        $(
+            #[test]
+            fn $name() {
+                use microfft::complex::{cfft_any, cfft_any_scratch_len};
+
+                let input = test_input($N);
+
+                let expected = rust_fft_any(&input);
+                let mut result = input.clone();
+                let mut scratch = vec![Complex32::default(); cfft_any_scratch_len($N)];
+                let result = cfft_any(&mut result, &mut scratch).unwrap();
+                assert_spectrum_eq(result, &expected);
+            }
+        )*
+    };
+}
+
+any_tests! {
+    any_1: 1,
+    any_2: 2,
+    any_7: 7,
+    any_13: 13,
+    any_64: 64,
+    any_97: 97,
+    any_480: 480,
+    any_1000: 1000,
+    any_1021: 1021,
+    any_2048: 2048,
+}
+
+#[test]
+fn any_errors() {
+    use microfft::{
+        complex::{cfft_any, cfft_any_scratch_len},
+        FftError,
+    };
+
+    let mut input = vec![Complex32::default(); 70000];
+    let mut scratch = vec![Complex32::default(); cfft_any_scratch_len(70000)];
+    assert_eq!(
+        cfft_any(&mut input[..0], &mut scratch),
+        Err(FftError::TooSmall)
+    );
+    assert_eq!(cfft_any(&mut input, &mut scratch), Err(FftError::TooLarge));
+    assert_eq!(
+        cfft_any(&mut input[..7], &mut scratch[..31]),
+        Err(FftError::ScratchTooSmall)
+    );
+    assert!(cfft_any(&mut input[..7], &mut scratch[..32]).is_ok());
+}
+
+#[test]
+fn cfft_generic() {
+    let mut input = [Complex32::default(); 1024];
+    for (i, x) in input.iter_mut().enumerate() {
+        *x = Complex32::new(i as f32, -(i as f32));
+    }
+    let mut expected = input;
+
+    let expected = microfft::complex::cfft_1024(&mut expected);
+    let result = microfft::complex::cfft(&mut input);
+
+    assert_eq!(&result[..], &expected[..]);
+}
+
+#[test]
+fn rfft_generic() {
+    let mut input = [0.; 1024];
+    input.copy_from_slice(&test_signal(1024));
+    let mut expected = input;
+
+    let expected = microfft::real::rfft_1024(&mut expected);
+    let result: &mut [Complex32; 512] = microfft::real::rfft(&mut input);
+
+    assert_eq!(&result[..], &expected[..]);
+}
+
+#[test]
+fn try_cfft() {
+    use microfft::{complex::try_cfft, FftError};
+
+    let mut input: Vec<_> = (0..256)
+        .map(|i| i as f32)
+        .map(|f| Complex32::new(f, f))
+        .collect();
+    let mut expected = input.clone();
+
+    let expected = microfft::complex::cfft_256(&mut expected);
+    let result = try_cfft(&mut input).unwrap();
+    assert_eq!(result, expected);
+
+    let mut input = vec![Complex32::default(); 131072];
+    assert_eq!(try_cfft(&mut input[..0]), Err(FftError::TooSmall));
+    assert_eq!(try_cfft(&mut input[..1]), Err(FftError::TooSmall));
+    assert_eq!(try_cfft(&mut input[..3]), Err(FftError::NotPowerOfTwo));
+    assert_eq!(try_cfft(&mut input[..131072]), Err(FftError::TooLarge));
+}
+
+#[test]
+fn try_rfft() {
+    use microfft::{real::try_rfft, FftError};
+
+    let mut input = test_signal(256);
+    let mut expected = input.clone();
+
+    let expected = microfft::real::rfft_256(&mut expected);
+    let result = try_rfft(&mut input).unwrap();
+    assert_eq!(result, expected);
+
+    let mut input = vec![0.; 131072];
+    assert_eq!(try_rfft(&mut input[..0]), Err(FftError::TooSmall));
+    assert_eq!(try_rfft(&mut input[..1]), Err(FftError::TooSmall));
+    assert_eq!(try_rfft(&mut input[..100]), Err(FftError::NotPowerOfTwo));
+    assert_eq!(try_rfft(&mut input[..131072]), Err(FftError::TooLarge));
+}
+
+#[test]
+fn fft_trait() {
+    use microfft::{complex::CFftN64, real::RFftN64, Fft};
+
+    fn size<F: Fft>() -> (usize, usize) {
+        (F::N, F::LOG2_N)
+    }
+
+    fn run<F: Fft>(input: &mut F::Input) -> &mut F::Output {
+        F::transform(input)
+    }
+
+    assert_eq!(size::<CFftN64>(), (64, 6));
+    assert_eq!(size::<RFftN64>(), (64, 6));
+
+    let mut input = [0.; 64];
+    input.copy_from_slice(&test_signal(64));
+    let mut input_c = [Complex32::default(); 64];
+    for (c, x) in input_c.iter_mut().zip(input.iter()) {
+        c.re = *x;
+    }
+    let mut expected = input;
+
+    let expected = microfft::real::rfft_64(&mut expected);
+    let result = run::<RFftN64>(&mut input);
+    assert_eq!(&result[..], &expected[..]);
+
+    let expected = expected.to_vec();
+    let result = run::<CFftN64>(&mut input_c);
+    assert_abs_eq(&result[1..32], &expected[1..]);
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "f64")]
+
+mod common;
+
+use common::test_signal;
+use microfft::Scaling;
+use num_complex::Complex64;
+use rustfft::{algorithm::Radix4, FFT};
+
+fn rust_fft(input: &[Complex64]) -> Vec<Complex64> {
+    let len = input.len();
+    let fft = Radix4::new(len, false);
+    let mut input = input.to_vec();
+    let mut output = vec![Complex64::default(); len];
+    fft.process(&mut input, &mut output);
+    output
+}
+
+fn assert_approx_eq(xa: &[Complex64], xb: &[Complex64]) {
+    assert_eq!(xa.len(), xb.len());
+    for (a, b) in xa.iter().zip(xb) {
+        assert!((a - b).norm() < 1e-6);
+    }
+}
+
+macro_rules! fft64_tests {
+    ( $( $(#[$meta:meta])* $name:ident: ($N:expr, $cfft:ident, $icfft:ident, $rfft_packed:ident, $irfft:ident), )* ) => {
+        $(
+            $(#[$meta])*
+            #[test]
+            fn $name() {
+                let input: Vec<_> = test_signal($N)
+                    .into_iter()
+                    .map(f64::from)
+                    .map(|f| Complex64::new(f, -f / 2.))
+                    .collect();
+
+                let expected = rust_fft(&input);
+                let mut result = input.clone();
+                let result = microfft::complex64::$cfft(&mut result);
+                assert_approx_eq(result, &expected);
+
+                let result = microfft::complex64::$icfft(result, Scaling::Normalized);
+                assert_approx_eq(result, &input);
+
+                let mut real: Vec<_> = input.iter().map(|c| c.re).collect();
+                let input_c: Vec<_> = real.iter().map(|f| Complex64::new(*f, 0.)).collect();
+                let spectrum = rust_fft(&input_c);
+                let mut expected = spectrum[..($N / 2)].to_vec();
+                expected[0].im = spectrum[$N / 2].re;
+
+                let original = real.clone();
+                let result = microfft::real64::$rfft_packed(&mut real);
+                assert_approx_eq(result, &expected);
+
+                let result = microfft::real64::$irfft(result);
+                for (a, b) in original.iter().zip(result.iter()) {
+                    assert!((a - b).abs() < 1e-9);
+                }
+            }
+        )*
+    };
+}
+
+fft64_tests! {
+    fft64_2: (2, cfft_2, icfft_2, rfft_packed_2, irfft_2),
+    fft64_4: (4, cfft_4, icfft_4, rfft_packed_4, irfft_4),
+    fft64_8: (8, cfft_8, icfft_8, rfft_packed_8, irfft_8),
+    fft64_16: (16, cfft_16, icfft_16, rfft_packed_16, irfft_16),
+    fft64_32: (32, cfft_32, icfft_32, rfft_packed_32, irfft_32),
+    fft64_64: (64, cfft_64, icfft_64, rfft_packed_64, irfft_64),
+    fft64_128: (128, cfft_128, icfft_128, rfft_packed_128, irfft_128),
+    fft64_256: (256, cfft_256, icfft_256, rfft_packed_256, irfft_256),
+    fft64_512: (512, cfft_512, icfft_512, rfft_packed_512, irfft_512),
+    fft64_1024: (1024, cfft_1024, icfft_1024, rfft_packed_1024, irfft_1024),
+    fft64_2048: (2048, cfft_2048, icfft_2048, rfft_packed_2048, irfft_2048),
+    fft64_4096: (4096, cfft_4096, icfft_4096, rfft_packed_4096, irfft_4096),
+    #[cfg(any(
+        feature = "maxn-8192",
+        feature = "maxn-16384",
+        feature = "maxn-32768",
+        feature = "maxn-65536",
+    ))]
+    fft64_8192: (8192, cfft_8192, icfft_8192, rfft_packed_8192, irfft_8192),
+    #[cfg(any(feature = "maxn-16384", feature = "maxn-32768", feature = "maxn-65536"))]
+    fft64_16384: (16384, cfft_16384, icfft_16384, rfft_packed_16384, irfft_16384),
+    #[cfg(any(feature = "maxn-32768", feature = "maxn-65536"))]
+    fft64_32768: (32768, cfft_32768, icfft_32768, rfft_packed_32768, irfft_32768),
+    #[cfg(feature = "maxn-65536")]
+    fft64_65536: (65536, cfft_65536, icfft_65536, rfft_packed_65536, irfft_65536),
+}
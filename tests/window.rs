@@ -0,0 +1,144 @@
+use microfft::window::Window;
+use std::f64::consts::PI;
+
+fn cosine_sum(a: &[f64], i: usize, n: usize) -> f64 {
+    a.iter()
+        .enumerate()
+        .map(|(m, a)| {
+            let sign = if m % 2 == 0 { 1. } else { -1. };
+            sign * a * (2. * PI * (m * i) as f64 / n as f64).cos()
+        })
+        .sum()
+}
+
+fn bessel_i0(x: f64) -> f64 {
+    (1..200)
+        .scan(1., |term, k| {
+            *term *= (x / 2.).powi(2) / (k * k) as f64;
+            Some(*term)
+        })
+        .fold(1., |sum, term| sum + term)
+}
+
+fn reference(window: Window, i: usize, n: usize) -> f64 {
+    match window {
+        Window::Hann => cosine_sum(&[0.5, 0.5], i, n),
+        Window::Hamming => cosine_sum(&[0.54, 0.46], i, n),
+        Window::Blackman => cosine_sum(&[0.42, 0.5, 0.08], i, n),
+        Window::BlackmanHarris => cosine_sum(&[0.35875, 0.48829, 0.14128, 0.01168], i, n),
+        Window::FlatTop => cosine_sum(
+            &[
+                0.21557895,
+                0.41663158,
+                0.277263158,
+                0.083578947,
+                0.006947368,
+            ],
+            i,
+            n,
+        ),
+        Window::Kaiser { beta } => {
+            let beta = f64::from(beta);
+            let t = 2. * i as f64 / n as f64 - 1.;
+            bessel_i0(beta * (1. - t * t).sqrt()) / bessel_i0(beta)
+        }
+        _ => unreachable!(),
+    }
+}
+
+const WINDOWS: [Window; 7] = [
+    Window::Hann,
+    Window::Hamming,
+    Window::Blackman,
+    Window::BlackmanHarris,
+    Window::FlatTop,
+    Window::Kaiser { beta: 0. },
+    Window::Kaiser { beta: 8.6 },
+];
+
+#[test]
+fn window_values() {
+    // a size covered by the sine table and one that is not
+    for &n in &[64, 100] {
+        for &window in &WINDOWS {
+            let mut samples = vec![2.; n];
+            window.apply(&mut samples);
+            for (i, x) in samples.iter().enumerate() {
+                let expected = 2. * reference(window, i, n) as f32;
+                assert!(
+                    (x - expected).abs() < 1e-5,
+                    "{:?}: {} != {}",
+                    window,
+                    x,
+                    expected
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn window_symmetry() {
+    // periodic windows are symmetric around `n/2`
+    for &window in &WINDOWS {
+        for i in 1..32 {
+            let (a, b) = (window.value(i, 64), window.value(64 - i, 64));
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
+}
+
+#[test]
+fn window_gains() {
+    let n = 256;
+    for &window in &WINDOWS {
+        let values: Vec<_> = (0..n).map(|i| reference(window, i, n)).collect();
+        let coherent = values.iter().sum::<f64>() / n as f64;
+        let energy = values.iter().map(|w| w * w).sum::<f64>() / n as f64;
+        assert!((window.coherent_gain(n) - coherent as f32).abs() < 1e-5);
+        assert!((window.energy_gain(n) - energy as f32).abs() < 1e-5);
+    }
+
+    assert!((Window::Hann.coherent_gain(n) - 0.5).abs() < 1e-6);
+    assert!((Window::Hann.energy_gain(n) - 0.375).abs() < 1e-6);
+    assert!((Window::Kaiser { beta: 0. }.coherent_gain(n) - 1.).abs() < 1e-6);
+}
+
+#[test]
+fn window_amplitude_correction() {
+    // a sinusoid on the bin grid has the correct amplitude after dividing by
+    // the coherent gain
+    for &window in &WINDOWS {
+        let mut samples: Vec<_> = (0..128)
+            .map(|i| 3. * (2. * PI * 16. * i as f64 / 128.).cos() as f32)
+            .collect();
+        window.apply(&mut samples);
+        let spectrum = microfft::real::rfft_128(&mut samples);
+        let amplitude = spectrum[16].norm() * 2. / 128. / window.coherent_gain(128);
+        assert!((amplitude - 3.).abs() < 1e-4, "{:?}: {}", window, amplitude);
+    }
+}
+
+#[test]
+fn window_kaiser_large_beta() {
+    // the normalization is close to the largest `f32` value
+    let window = Window::Kaiser { beta: 80. };
+    for i in 0..64 {
+        let (w, expected) = (window.value(i, 64), reference(window, i, 64) as f32);
+        assert!(w.is_finite());
+        assert!((w - expected).abs() < 1e-5, "{} != {}", w, expected);
+    }
+    assert_eq!(window.value(32, 64), 1.);
+}
+
+#[test]
+#[should_panic]
+fn window_kaiser_beta_too_large() {
+    Window::Kaiser { beta: 90. }.apply(&mut [1.; 16]);
+}
+
+#[test]
+#[should_panic]
+fn window_index_out_of_range() {
+    Window::Hann.value(8, 8);
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::test_signal;
+use microfft::{zoom, Complex32};
+use num_complex::Complex64;
+use std::f64::consts::PI;
+
+fn dft_at(input: &[Complex32], freq: f64) -> Complex32 {
+    let sum: Complex64 = input
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let x = Complex64::new(f64::from(x.re), f64::from(x.im));
+            x * Complex64::from_polar(&1., &(-2. * PI * freq * i as f64))
+        })
+        .sum();
+    Complex32::new(sum.re as f32, sum.im as f32)
+}
+
+fn assert_zoom_eq(n: usize, k: usize, start: f32, step: f32) {
+    let input: Vec<_> = test_signal(n)
+        .into_iter()
+        .map(|f| Complex32::new(f, -f / 2.))
+        .collect();
+
+    let mut output = vec![Complex32::default(); k];
+    let mut scratch = vec![Complex32::default(); zoom::scratch_len(n, k)];
+    let result = zoom::zoom_fft(&input, start, step, &mut output, &mut scratch).unwrap();
+
+    let max = input.iter().map(|c| c.norm()).sum::<f32>();
+    for (j, y) in result.iter().enumerate() {
+        let freq = f64::from(start) + f64::from(step) * j as f64;
+        let expected = dft_at(&input, freq);
+        assert!((y - expected).norm() <= max * 1e-5);
+    }
+}
+
+#[test]
+fn zoom_narrow_band() {
+    assert_zoom_eq(1000, 200, 0.1, 1e-4);
+    assert_zoom_eq(1024, 64, 0.45, 1e-3);
+    assert_zoom_eq(17, 5, -0.2, 0.01);
+    assert_zoom_eq(1, 1, 0.3, 0.1);
+}
+
+#[test]
+fn zoom_large_input() {
+    assert_zoom_eq(3000, 100, 0.25, 1e-5);
+}
+
+#[test]
+fn zoom_full_spectrum() {
+    let mut input: Vec<_> = test_signal(64)
+        .into_iter()
+        .map(|f| Complex32::new(f, f))
+        .collect();
+
+    let mut output = vec![Complex32::default(); 64];
+    let mut scratch = vec![Complex32::default(); zoom::scratch_len(64, 64)];
+    let result = zoom::zoom_fft(&input, 0., 1. / 64., &mut output, &mut scratch).unwrap();
+    let expected = microfft::complex::cfft_64(&mut input);
+
+    for (a, b) in result.iter().zip(expected.iter()) {
+        assert!((a - b).norm() < 0.01);
+    }
+}
+
+#[test]
+fn zoom_errors() {
+    use microfft::FftError;
+
+    let input = vec![Complex32::default(); 70000];
+    let mut output = vec![Complex32::default(); 10];
+    let mut scratch = vec![Complex32::default(); zoom::scratch_len(70000, 10)];
+
+    let result = zoom::zoom_fft(&input[..0], 0., 0.1, &mut output, &mut scratch);
+    assert_eq!(result, Err(FftError::TooSmall));
+    let result = zoom::zoom_fft(&input[..10], 0., 0.1, &mut output[..0], &mut scratch);
+    assert_eq!(result, Err(FftError::TooSmall));
+    let result = zoom::zoom_fft(&input, 0., 0.1, &mut output, &mut scratch);
+    assert_eq!(result, Err(FftError::TooLarge));
+    let result = zoom::zoom_fft(&input[..10], 0., 0.1, &mut output, &mut scratch[..63]);
+    assert_eq!(result, Err(FftError::ScratchTooSmall));
+    let result = zoom::zoom_fft(&input[..10], 0., 0.1, &mut output, &mut scratch[..64]);
+    assert!(result.is_ok());
+}